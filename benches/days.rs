@@ -0,0 +1,75 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use advent_of_code_2024::{
+    days::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11},
+    solution::Solution,
+};
+
+mod inputs;
+
+/// Benchmarks parsing and both parts of a day separately.
+fn bench_day<S: Solution>(c: &mut Criterion, input: &str) {
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input)).unwrap()));
+
+    let parsed = S::parse(input).unwrap();
+
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day01::Day01>(c, &inputs::day01());
+    bench_day::<day02::Day02>(c, &inputs::day02());
+    bench_day::<day03::Day03>(c, &inputs::day03());
+    bench_day::<day04::Day04>(c, &inputs::day04());
+    bench_day::<day05::Day05>(c, &inputs::day05());
+    bench_day::<day07::Day07>(c, &inputs::day07());
+    bench_day::<day08::Day08>(c, &inputs::day08());
+    bench_day::<day09::Day09>(c, &inputs::day09());
+    bench_day::<day10::Day10>(c, &inputs::day10());
+    bench_day::<day11::Day11>(c, &inputs::day11());
+}
+
+/// Day 6 is slow enough to need fewer samples.
+fn day06(c: &mut Criterion) {
+    bench_day::<day06::Day06>(c, &inputs::day06());
+}
+
+/// The hot spots of the slower days on their own.
+fn hot_spots(c: &mut Criterion) {
+    let lab = day06::parse(&inputs::day06()).unwrap();
+
+    c.bench_function("day06/possible_obstructions", |b| {
+        b.iter(|| {
+            day06::possible_obstructions(
+                lab.guard.clone(),
+                lab.map.clone(),
+                black_box(&lab.visited),
+            )
+        })
+    });
+
+    let numbers: Vec<u64> = (1..=12).collect();
+
+    c.bench_function("day07/possible_results", |b| {
+        b.iter(|| day07::possible_results(black_box(&numbers), true).count())
+    });
+
+    let stones = day11::parse(&inputs::day11()).unwrap();
+
+    c.bench_function("day11/simulate_stones_75", |b| {
+        b.iter(|| day11::simulate_stones(black_box(&stones), 75))
+    });
+}
+
+criterion_group!(benches, days);
+criterion_group! {
+    name = slow;
+    config = Criterion::default().sample_size(10);
+    targets = day06, hot_spots
+}
+criterion_main!(benches, slow);
@@ -0,0 +1,231 @@
+//! Deterministic puzzle input generators, so the benchmarks don't depend on
+//! personal inputs. The generated inputs roughly match the size of the real
+//! ones.
+
+use std::fmt::Write;
+
+use advent_of_code_2024::days::day06;
+
+/// Small xorshift generator, good enough to produce varied inputs.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Random number in `low..high`.
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        low + self.next_u64() % (high - low)
+    }
+
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.range(0, items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            let other = self.range(0, idx as u64 + 1) as usize;
+            items.swap(idx, other);
+        }
+    }
+}
+
+fn grid(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> char) -> String {
+    let mut input = String::new();
+
+    for y in 0..height {
+        input.extend((0..width).map(|x| cell(x, y)));
+        input.push('\n');
+    }
+
+    input
+}
+
+pub fn day01() -> String {
+    let mut rng = Rng::new(1);
+    let mut input = String::new();
+
+    for _ in 0..1000 {
+        let l = rng.range(10000, 100000);
+        let r = rng.range(10000, 100000);
+        writeln!(input, "{l}   {r}").unwrap();
+    }
+
+    input
+}
+
+pub fn day02() -> String {
+    let mut rng = Rng::new(2);
+    let mut input = String::new();
+
+    for _ in 0..1000 {
+        let len = rng.range(5, 9);
+        let mut level = rng.range(10, 90) as i64;
+        let mut levels = Vec::new();
+
+        for _ in 0..len {
+            levels.push(level.to_string());
+            level += rng.range(0, 7) as i64 - 3;
+        }
+
+        writeln!(input, "{}", levels.join(" ")).unwrap();
+    }
+
+    input
+}
+
+pub fn day03() -> String {
+    let mut rng = Rng::new(3);
+    let mut input = String::new();
+    let junk = [
+        '%', '&', '[', ']', '!', '@', '^', '(', ')', ',', ' ', 'm', 'u', 'l',
+    ];
+
+    for _ in 0..3000 {
+        match rng.range(0, 10) {
+            0 => input.push_str("do()"),
+            1 => input.push_str("don't()"),
+            2..=5 => write!(input, "mul({},{})", rng.range(1, 1000), rng.range(1, 1000)).unwrap(),
+            _ => input.extend((0..rng.range(1, 8)).map(|_| rng.pick(&junk))),
+        }
+    }
+
+    input.push('\n');
+    input
+}
+
+pub fn day04() -> String {
+    let mut rng = Rng::new(4);
+    grid(140, 140, |_, _| rng.pick(&['X', 'M', 'A', 'S']))
+}
+
+pub fn day05() -> String {
+    let mut rng = Rng::new(5);
+    let mut input = String::new();
+
+    let mut pages: Vec<u64> = (10..59).collect();
+    rng.shuffle(&mut pages);
+
+    // Every pair gets a rule, following the shuffled order
+    for (idx, page) in pages.iter().enumerate() {
+        for later in &pages[idx + 1..] {
+            writeln!(input, "{page}|{later}").unwrap();
+        }
+    }
+
+    input.push('\n');
+
+    for _ in 0..200 {
+        let len = rng.range(2, 12) * 2 + 1;
+        let mut update = pages.clone();
+        rng.shuffle(&mut update);
+        update.truncate(len as usize);
+
+        let update: Vec<String> = update.iter().map(u64::to_string).collect();
+        writeln!(input, "{}", update.join(",")).unwrap();
+    }
+
+    input
+}
+
+pub fn day06() -> String {
+    let size = 130;
+
+    // Random maps may trap the guard without extra obstructions, keep
+    // trying until one lets the guard escape.
+    (6..)
+        .map(|seed| {
+            let mut rng = Rng::new(seed);
+            grid(size, size, |x, y| {
+                if (x, y) == (size / 2, size / 2) {
+                    '^'
+                } else if rng.range(0, 100) < 2 {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+        })
+        .find(|input| day06::parse(input).is_ok())
+        .unwrap()
+}
+
+pub fn day07() -> String {
+    let mut rng = Rng::new(7);
+    let mut input = String::new();
+
+    for _ in 0..850 {
+        let len = rng.range(2, 10);
+        let numbers: Vec<u64> = (0..len).map(|_| rng.range(1, 100)).collect();
+
+        // Build the result from random operators so some equations hold
+        let result = numbers[1..]
+            .iter()
+            .fold(numbers[0], |acc, n| match rng.range(0, 3) {
+                0 => acc + n,
+                1 => acc * n,
+                _ => format!("{acc}{n}").parse().unwrap(),
+            });
+
+        let numbers: Vec<String> = numbers.iter().map(u64::to_string).collect();
+        writeln!(input, "{result}: {}", numbers.join(" ")).unwrap();
+    }
+
+    input
+}
+
+pub fn day08() -> String {
+    let mut rng = Rng::new(8);
+    let frequencies: Vec<char> = ('a'..='z').chain('A'..='Z').chain('0'..='9').collect();
+
+    grid(50, 50, |_, _| {
+        if rng.range(0, 100) < 8 {
+            rng.pick(&frequencies)
+        } else {
+            '.'
+        }
+    })
+}
+
+pub fn day09() -> String {
+    let mut rng = Rng::new(9);
+    let mut input: String = (0..19999)
+        .map(|idx| {
+            let low = if idx % 2 == 0 { 1 } else { 0 };
+            char::from_digit(rng.range(low, 10) as u32, 10).unwrap()
+        })
+        .collect();
+
+    input.push('\n');
+    input
+}
+
+pub fn day10() -> String {
+    let mut rng = Rng::new(10);
+
+    // Diagonal slopes make for plenty of trails, with some noise to break
+    // them up.
+    grid(50, 50, |x, y| {
+        let height = if rng.range(0, 10) == 0 {
+            rng.range(0, 10) as usize
+        } else {
+            (x + y) % 10
+        };
+        char::from_digit(height as u32, 10).unwrap()
+    })
+}
+
+pub fn day11() -> String {
+    let mut rng = Rng::new(11);
+    let stones: Vec<String> = (0..8).map(|_| rng.range(0, 1000000).to_string()).collect();
+
+    stones.join(" ") + "\n"
+}
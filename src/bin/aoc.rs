@@ -0,0 +1,264 @@
+use std::{env, io, path::PathBuf, process::ExitCode};
+
+use advent_of_code_2024::{
+    days,
+    error::AocError,
+    output::{answers_json, print_answers, print_timing_summary, OutputFormat},
+    solution::{Answers, Timings},
+    verify::{KnownAnswers, Verdict},
+    InputSource,
+};
+
+const USAGE: &str = "\
+Usage: aoc run <DAY | FIRST..LAST | FIRST..=LAST | --all> [--input <PATH | ->] [--time]
+               [--format <text | json | jsonl>]
+       aoc verify [DAY | FIRST..LAST | FIRST..=LAST | --all] [--input <PATH | ->]
+                  [--answers <PATH>]
+
+Inputs are read from $AOC_INPUT_DIR/dayNN.txt, or inputs/dayNN.txt when the
+variable isn't set. --input overrides the file of a single day, use - to read
+from stdin. --time shows how long each step took, and a summary table when
+running several days. --format json prints an object per day, or an array of
+them for several days, while jsonl prints one object per line.
+
+verify runs every day (or the selected ones) and compares the answers with
+answers.toml, or the file given with --answers. Days without an input file
+are reported as unknown.";
+
+const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+/// Parses a day selection such as `7`, `1..4` or `1..=11`. Empty ranges
+/// and day 0 are rejected.
+fn parse_days(spec: &str) -> Option<Vec<u8>> {
+    let days: Vec<u8> = if let Some((first, last)) = spec.split_once("..=") {
+        let first: u8 = first.parse().ok()?;
+        let last: u8 = last.parse().ok()?;
+        (first..=last).collect()
+    } else if let Some((first, last)) = spec.split_once("..") {
+        let first: u8 = first.parse().ok()?;
+        let last: u8 = last.parse().ok()?;
+        (first..last).collect()
+    } else {
+        vec![spec.parse().ok()?]
+    };
+
+    (!days.is_empty() && !days.contains(&0)).then_some(days)
+}
+
+struct Args {
+    days: Option<Vec<u8>>,
+    input: Option<InputSource>,
+    show_time: bool,
+    format: OutputFormat,
+    answers: PathBuf,
+}
+
+/// Parses the arguments following `command`, rejecting options that only
+/// apply to the other command.
+fn parse_args(command: &str, args: &[String]) -> Result<Args, String> {
+    let mut days: Option<Vec<u8>> = None;
+    let mut input: Option<InputSource> = None;
+    let mut show_time = false;
+    let mut format = OutputFormat::Text;
+    let mut answers = PathBuf::from(DEFAULT_ANSWERS_FILE);
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        if days.is_some() && (arg == "--all" || !arg.starts_with("--")) {
+            return Err(format!("Only one day selection can be given, found {arg}"));
+        }
+
+        match arg.as_str() {
+            "--all" => days = Some(days::available().collect()),
+            "--time" if command == "run" => show_time = true,
+            "--input" => {
+                let path = args
+                    .next()
+                    .ok_or(AocError::MissingArgument("input path").to_string())?;
+                input = Some(InputSource::from_arg(path));
+            }
+            "--format" if command == "run" => {
+                let name = args
+                    .next()
+                    .ok_or(AocError::MissingArgument("output format").to_string())?;
+                format = OutputFormat::from_name(name)
+                    .ok_or(format!("Unknown output format: {name}"))?;
+            }
+            "--answers" if command == "verify" => {
+                let path = args
+                    .next()
+                    .ok_or(AocError::MissingArgument("answers path").to_string())?;
+                answers = PathBuf::from(path);
+            }
+            option if option.starts_with("--") => {
+                return Err(format!("Unknown option for aoc {command}: {option}"));
+            }
+            spec => {
+                let selected = parse_days(spec).ok_or(format!("Invalid day selection: {spec}"))?;
+                days = Some(selected);
+            }
+        }
+    }
+
+    if input.is_some() && days.as_ref().is_none_or(|days| days.len() != 1) {
+        return Err("--input can only be used when running a single day".to_string());
+    }
+
+    Ok(Args {
+        days,
+        input,
+        show_time,
+        format,
+        answers,
+    })
+}
+
+fn read_input(day: u8, input: Option<&InputSource>) -> Result<String, AocError> {
+    match input {
+        Some(source) => source.read(),
+        None => InputSource::for_day(day).read(),
+    }
+}
+
+fn solve_day(day: u8, input: &str) -> Result<Answers, String> {
+    let solver = days::solver(day).ok_or(format!("Day {day} is not solved yet"))?;
+
+    solver(input).map_err(|err| format!("Day {day}: {err}"))
+}
+
+fn run_day(day: u8, input: Option<&InputSource>) -> Result<Answers, String> {
+    if days::solver(day).is_none() {
+        return Err(format!("Day {day} is not solved yet"));
+    }
+
+    let input = read_input(day, input).map_err(|err| err.to_string())?;
+
+    solve_day(day, &input)
+}
+
+fn run(args: Args) -> ExitCode {
+    let Some(days) = args.days else {
+        eprintln!("{}", AocError::MissingArgument("day selection"));
+        return ExitCode::FAILURE;
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    let mut timings: Vec<(u8, Timings)> = Vec::new();
+    let mut json_objects: Vec<String> = Vec::new();
+
+    for &day in &days {
+        let answers = match run_day(day, args.input.as_ref()) {
+            Ok(answers) => answers,
+            Err(err) => {
+                eprintln!("{err}");
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        match args.format {
+            OutputFormat::Text => {
+                println!("Day {day}");
+                print_answers(&answers, args.show_time);
+            }
+            OutputFormat::Json => json_objects.push(answers_json(day, &answers)),
+            OutputFormat::JsonLines => println!("{}", answers_json(day, &answers)),
+        }
+
+        timings.push((day, answers.timings));
+    }
+
+    match args.format {
+        OutputFormat::Text if args.show_time && days.len() > 1 => {
+            println!();
+            print_timing_summary(&timings);
+        }
+        OutputFormat::Json if days.len() == 1 => {
+            if let Some(object) = json_objects.first() {
+                println!("{object}");
+            }
+        }
+        OutputFormat::Json => println!("[{}]", json_objects.join(",")),
+        _ => (),
+    }
+
+    status
+}
+
+fn verify(args: Args) -> ExitCode {
+    let known = match KnownAnswers::load(&args.answers) {
+        Ok(known) => known,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let days = args.days.unwrap_or_else(|| days::available().collect());
+    let mut status = ExitCode::SUCCESS;
+
+    for day in days {
+        // Without an input there is nothing to compare, which isn't a failure
+        let answers = match read_input(day, args.input.as_ref()) {
+            Err(AocError::Io { path, source }) if source.kind() == io::ErrorKind::NotFound => {
+                println!("Day {day:>2}: unknown (no input at {path})");
+                continue;
+            }
+            input => input.map_err(|err| err.to_string()),
+        }
+        .and_then(|input| solve_day(day, &input));
+
+        let answers = match answers {
+            Ok(answers) => answers,
+            Err(err) => {
+                eprintln!("{err}");
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let verdicts: Vec<String> = [(1, &answers.part1), (2, &answers.part2)]
+            .into_iter()
+            .map(|(part, answer)| match known.check(day, part, answer) {
+                Verdict::Pass => format!("part {part} pass"),
+                Verdict::Unknown => format!("part {part} unknown ({answer})"),
+                Verdict::Fail { expected } => {
+                    status = ExitCode::FAILURE;
+                    format!("part {part} FAIL (expected {expected}, got {answer})")
+                }
+            })
+            .collect();
+
+        println!("Day {day:>2}: {}", verdicts.join(", "));
+    }
+
+    status
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let Some((command, rest)) = args.split_first() else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    let run_command: fn(Args) -> ExitCode = match command.as_str() {
+        "run" => run,
+        "verify" => verify,
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let args = match parse_args(command, rest) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    run_command(args)
+}
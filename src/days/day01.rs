@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use crate::{
+    error::{parse_field, AocError},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone)]
+pub struct LocationLists {
+    pub left: Vec<u64>,
+    pub right: Vec<u64>,
+}
+
+pub fn parse(input: &str) -> Result<LocationLists, AocError> {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    for (idx, line) in input.lines().enumerate() {
+        let line_no = idx + 1;
+        let (l, r) = line.split_once("   ").ok_or_else(|| {
+            AocError::parse(line_no, 1, "Expected two numbers separated by three spaces")
+        })?;
+
+        left.push(parse_field(line_no, line, l)?);
+        right.push(parse_field(line_no, line, r)?);
+    }
+
+    Ok(LocationLists { left, right })
+}
+
+/// Total distance between the lists once both are sorted.
+pub fn part1(lists: &LocationLists) -> u64 {
+    let mut left_nums = lists.left.clone();
+    let mut right_nums = lists.right.clone();
+
+    left_nums.sort();
+    right_nums.sort();
+
+    left_nums
+        .iter()
+        .zip(right_nums.iter())
+        .map(|(l, r)| l.abs_diff(*r))
+        .sum()
+}
+
+/// Sum of every left number multiplied by its count on the right list.
+pub fn part2(lists: &LocationLists) -> u64 {
+    let mut right_counts: HashMap<u64, u64> = HashMap::new();
+
+    for number in &lists.right {
+        right_counts
+            .entry(*number)
+            .and_modify(|num| *num += 1)
+            .or_insert(1);
+    }
+
+    lists
+        .left
+        .iter()
+        .map(|num| num * right_counts.get(num).cloned().unwrap_or(0))
+        .sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = LocationLists;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(lists: &Self::Input) -> u64 {
+        part1(lists)
+    }
+
+    fn part2(lists: &Self::Input) -> u64 {
+        part2(lists)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input), 11);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input), 31);
+    }
+}
@@ -0,0 +1,149 @@
+use std::cmp::Ordering;
+
+use crate::{
+    error::{parse_field, AocError},
+    solution::Solution,
+};
+
+pub fn is_report_safe(report: &[u64]) -> bool {
+    if report.len() < 2 {
+        return true;
+    }
+
+    let expected_order = report[0].cmp(&report[1]);
+    if expected_order == Ordering::Equal {
+        return false;
+    }
+
+    for pair in report.windows(2) {
+        if let [l, r] = pair {
+            if l.cmp(r) != expected_order {
+                return false;
+            }
+
+            let diff = l.abs_diff(*r);
+            if !(1..=3).contains(&diff) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+pub fn is_report_safe_dampened(report: &[u64]) -> bool {
+    if is_report_safe(report) {
+        return true;
+    }
+
+    let safe_found = (0..report.len()).find(|i| {
+        let (l, r) = report.split_at(*i);
+
+        let l_iter = l.iter().chain(r.iter().skip(1));
+        let r_iter = l.iter().chain(r.iter().skip(1)).skip(1);
+
+        let mut expected_order: Option<Ordering> = None;
+
+        for (l, r) in l_iter.zip(r_iter) {
+            if expected_order.is_none() {
+                let order = l.cmp(r);
+
+                if order == Ordering::Equal {
+                    return false;
+                }
+
+                expected_order = Some(order);
+            }
+
+            if l.cmp(r) != *expected_order.as_ref().unwrap() {
+                return false;
+            }
+
+            let diff = l.abs_diff(*r);
+
+            if !(1..=3).contains(&diff) {
+                return false;
+            }
+        }
+
+        true
+    });
+
+    safe_found.is_some()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<u64>>, AocError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            line.split(" ")
+                .map(|level| parse_field(idx + 1, line, level))
+                .collect()
+        })
+        .collect()
+}
+
+/// Number of safe reports.
+pub fn part1(reports: &[Vec<u64>]) -> usize {
+    reports
+        .iter()
+        .filter(|report| is_report_safe(report))
+        .count()
+}
+
+/// Number of safe reports when a single bad level can be removed.
+pub fn part2(reports: &[Vec<u64>]) -> usize {
+    reports
+        .iter()
+        .filter(|report| is_report_safe_dampened(report))
+        .count()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<u64>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(reports: &Self::Input) -> usize {
+        part1(reports)
+    }
+
+    fn part2(reports: &Self::Input) -> usize {
+        part2(reports)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input), 2);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input), 4);
+    }
+}
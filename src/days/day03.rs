@@ -0,0 +1,173 @@
+use crate::{error::AocError, solution::Solution};
+
+#[derive(Debug)]
+pub enum Instruction {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+impl Instruction {
+    pub fn value(&self) -> u64 {
+        match self {
+            Instruction::Mul(lhs, rhs) => u64::from(*lhs) * u64::from(*rhs),
+            Instruction::Do => 0,
+            Instruction::Dont => 0,
+        }
+    }
+}
+
+pub type ParseResult<'src, T> = Result<(&'src str, T), &'src str>;
+
+pub fn fixed_string<'s>(source: &'s str, expected: &'static str) -> ParseResult<'s, ()> {
+    match source.strip_prefix(expected) {
+        Some(advanced) => Ok((advanced, ())),
+        None => Err(source),
+    }
+}
+
+/// Operands of `mul` have 1 to 3 digits.
+const MAX_DIGITS: usize = 3;
+
+pub fn number(source: &str) -> ParseResult<'_, u32> {
+    let len = source
+        .bytes()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+
+    if !(1..=MAX_DIGITS).contains(&len) {
+        return Err(source);
+    }
+
+    let value: u32 = source[..len].parse().or(Err(source))?;
+    Ok((&source[len..], value))
+}
+
+const IDENTS: [&str; 3] = ["mul", "don't", "do"];
+
+pub fn identifier(source: &str) -> ParseResult<'_, &str> {
+    for ident in IDENTS {
+        if let Some(advanced) = source.strip_prefix(ident) {
+            return Ok((advanced, ident));
+        }
+    }
+
+    Err(source)
+}
+
+pub fn parse_call(source: &str) -> ParseResult<'_, Instruction> {
+    let (advanced, ident) = identifier(source).or(Err(source))?;
+    let (advanced, _) = fixed_string(advanced, "(").or(Err(source))?;
+
+    let (advanced, instruction) = match ident {
+        "mul" => {
+            let (advanced, lhs) = number(advanced).or(Err(source))?;
+            let (advanced, _) = fixed_string(advanced, ",").or(Err(source))?;
+            let (advanced, rhs) = number(advanced).or(Err(source))?;
+            (advanced, Instruction::Mul(lhs, rhs))
+        }
+        "don't" => (advanced, Instruction::Dont),
+        "do" => (advanced, Instruction::Do),
+        _ => return Err(source),
+    };
+
+    let (advanced, _) = fixed_string(advanced, ")").or(Err(source))?;
+
+    Ok((advanced, instruction))
+}
+
+/// Extracts every well formed instruction from the corrupted memory. Any
+/// other text is skipped, so this never fails.
+pub fn parse(input: &str) -> Result<Vec<Instruction>, AocError> {
+    let mut source = input;
+
+    let mut instructions: Vec<Instruction> = Vec::new();
+
+    while !source.is_empty() {
+        match parse_call(source) {
+            Ok((advanced, instruction)) => {
+                instructions.push(instruction);
+                source = advanced;
+            }
+            Err(advanced) => {
+                let mut chars = advanced.chars();
+                chars.next();
+                source = chars.as_str();
+            }
+        }
+    }
+
+    Ok(instructions)
+}
+
+/// Sum of all multiplications.
+pub fn part1(instructions: &[Instruction]) -> u64 {
+    instructions.iter().map(|ins| ins.value()).sum()
+}
+
+/// Sum of the multiplications enabled by `do()` and `don't()`.
+pub fn part2(instructions: &[Instruction]) -> u64 {
+    instructions
+        .iter()
+        .fold((true, 0), |(enable, acc), ins| match ins {
+            Instruction::Do => (true, acc),
+            Instruction::Dont => (false, acc),
+            Instruction::Mul(_, _) => {
+                let value = if enable { ins.value() } else { 0 };
+                (enable, value + acc)
+            }
+        })
+        .1
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Instruction>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> u64 {
+        part1(instructions)
+    }
+
+    fn part2(instructions: &Self::Input) -> u64 {
+        part2(instructions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_PART1: &str =
+        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+
+    const EXAMPLE_PART2: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE_PART1).unwrap();
+        assert_eq!(part1(&input), 161);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE_PART2).unwrap();
+        assert_eq!(part2(&input), 48);
+    }
+
+    #[test]
+    fn operands_have_at_most_three_digits() {
+        let input = parse("mul(1234,5)mul(999,999)mul(99999,99999)").unwrap();
+        assert_eq!(input.len(), 1);
+        assert_eq!(part1(&input), 998001);
+    }
+}
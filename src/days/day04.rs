@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+
+use crate::direction::Direction8;
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::solution::Solution;
+use crate::vector::Vector2d;
+
+pub const XMAS_DIRECTIONS: [Direction8; 8] = Direction8::ALL;
+
+/// Two MAS crossing at the A, in one of its orientations. Dots match
+/// anything.
+pub const X_MAS: &str = "\
+M.S
+.A.
+M.S
+";
+
+pub fn find_in_grid(
+    target: &str,
+    grid: &Grid<char>,
+    start: Vector2d<usize>,
+    direction: Direction8,
+) -> bool {
+    grid.ray(start, direction)
+        .take(target.chars().count())
+        .copied()
+        .eq(target.chars())
+}
+
+pub fn find_xmas(
+    grid: &Grid<char>,
+    start: Vector2d<usize>,
+    direction: Direction8,
+) -> Option<Vector2d<usize>> {
+    if find_in_grid("XMAS", grid, start, direction) {
+        Some(start)
+    } else {
+        None
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid<char>, AocError> {
+    Grid::from_text(input, |ch| ch)
+}
+
+/// Number of times XMAS appears in any direction.
+pub fn part1(grid: &Grid<char>) -> usize {
+    let mut xmas: HashSet<(Vector2d<usize>, Direction8)> = HashSet::new();
+
+    for y in 0..grid.height() {
+        for x in 0..grid.width() {
+            let pos = Vector2d::new(x, y);
+
+            for dir in XMAS_DIRECTIONS {
+                if let Some(pos) = find_xmas(grid, pos, dir) {
+                    xmas.insert((pos, dir));
+                }
+            }
+        }
+    }
+
+    xmas.len()
+}
+
+/// Number of MAS crosses.
+pub fn part2(grid: &Grid<char>) -> usize {
+    let pattern = Grid::from_text(X_MAS, |ch| (ch != '.').then_some(ch))
+        .expect("X-MAS pattern is a valid grid");
+
+    grid.find_pattern(&pattern).len()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        part1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        part2(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input), 18);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input), 9);
+    }
+}
@@ -0,0 +1,189 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+use crate::{
+    error::{parse_field, AocError},
+    solution::Solution,
+};
+
+pub type Rules = HashMap<u8, HashSet<u8>>;
+
+pub fn parse_rules(rules: &str) -> Result<Rules, AocError> {
+    let mut rule_map: HashMap<u8, HashSet<u8>> = HashMap::new();
+
+    for (idx, line) in rules.lines().enumerate() {
+        let line_no = idx + 1;
+        let (page, precedes) = line
+            .split_once("|")
+            .ok_or_else(|| AocError::parse(line_no, 1, "Expected a rule like 'X|Y'"))?;
+        let page: u8 = parse_field(line_no, line, page)?;
+        let precedes: u8 = parse_field(line_no, line, precedes)?;
+
+        rule_map.entry(page).or_default().insert(precedes);
+    }
+
+    Ok(rule_map)
+}
+
+/// Parses the update section, whose first line is line `first_line` of the
+/// whole input.
+pub fn parse_updates(updates: &str, first_line: usize) -> Result<Vec<Vec<u8>>, AocError> {
+    updates
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            line.split(',')
+                .map(|num| parse_field(first_line + idx, line, num))
+                .collect()
+        })
+        .collect()
+}
+
+pub fn is_update_ordered(rules: &Rules, update: &[u8]) -> bool {
+    for (idx, page) in update.iter().enumerate() {
+        for next in update.iter().skip(1 + idx) {
+            let precedes = match rules.get(next) {
+                Some(prec) => prec,
+                None => continue,
+            };
+            if precedes.contains(page) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+pub fn fix_update(rules: &Rules, update: &[u8]) -> Vec<u8> {
+    let mut new: Vec<u8> = Vec::from(update);
+
+    new.sort_by(|a, b| {
+        use Ordering::*;
+        match rules.get(a) {
+            Some(precedes) => {
+                if precedes.contains(b) {
+                    Less
+                } else {
+                    Greater
+                }
+            }
+            None => Equal,
+        }
+    });
+
+    new
+}
+
+pub struct Manual {
+    pub rules: Rules,
+    pub updates: Vec<Vec<u8>>,
+}
+
+pub fn parse(input: &str) -> Result<Manual, AocError> {
+    let (rules, updates) = input.split_once("\n\n").ok_or(AocError::InvalidInput(
+        "Expected rules and updates separated by a blank line".to_string(),
+    ))?;
+
+    // Rules, then the blank line
+    let first_update_line = rules.lines().count() + 2;
+
+    Ok(Manual {
+        rules: parse_rules(rules)?,
+        updates: parse_updates(updates, first_update_line)?,
+    })
+}
+
+/// Sum of the middle pages of the correctly ordered updates.
+pub fn part1(manual: &Manual) -> u64 {
+    manual
+        .updates
+        .iter()
+        .filter(|up| is_update_ordered(&manual.rules, up))
+        .map(|up| up[up.len() / 2] as u64)
+        .sum()
+}
+
+/// Sum of the middle pages of the incorrectly ordered updates after fixing
+/// them.
+pub fn part2(manual: &Manual) -> u64 {
+    manual
+        .updates
+        .iter()
+        .filter(|up| !is_update_ordered(&manual.rules, up))
+        .map(|up| fix_update(&manual.rules, up))
+        .map(|up| up[up.len() / 2] as u64)
+        .sum()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Manual;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(manual: &Self::Input) -> u64 {
+        part1(manual)
+    }
+
+    fn part2(manual: &Self::Input) -> u64 {
+        part2(manual)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input), 143);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input), 123);
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::HashSet;
+
+use crate::{
+    direction::{AsVector, Direction},
+    error::AocError,
+    grid::Grid,
+    solution::Solution,
+    vector::Vector2d,
+};
+
+pub type Position = Vector2d<i16>;
+pub type PositionSet = HashSet<Position>;
+
+#[derive(Clone)]
+pub struct Map {
+    pub width: i16,
+    pub height: i16,
+    pub obstacles: PositionSet,
+    pub additional: Option<Position>,
+}
+
+impl Map {
+    pub fn is_blocked(&self, position: Position) -> bool {
+        self.obstacles.contains(&position) || self.additional.is_some_and(|pos| pos == position)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Guard {
+    pub position: Position,
+    pub direction: Direction,
+}
+
+impl Guard {
+    /// Return value tells if guard bonked
+    pub fn do_move(&mut self, map: &Map) -> bool {
+        let new_pos = self.next_position();
+
+        if map.is_blocked(new_pos) {
+            self.rotate();
+            true
+        } else {
+            self.position = new_pos;
+            false
+        }
+    }
+
+    pub fn next_position(&self) -> Position {
+        self.position + self.direction.as_vector()
+    }
+
+    pub fn rotate(&mut self) {
+        self.direction = self.direction.rotate(1);
+    }
+}
+
+pub fn follow_path(mut guard: Guard, map: &Map) -> Option<PositionSet> {
+    let mut visited: PositionSet = HashSet::new();
+    let mut bonked: HashSet<(Position, Direction)> = HashSet::new();
+
+    loop {
+        let pos = guard.position;
+
+        if pos.x < 0 || pos.x >= map.width || pos.y < 0 || pos.y >= map.height {
+            break Some(visited);
+        }
+
+        visited.insert(pos);
+
+        // If the guard:
+        //   moves and bonks against an obstacle
+        //   and that obstacle has been bonked before
+        //   then we're in an infinite loop
+        if guard.do_move(map) && !bonked.insert((guard.position, guard.direction)) {
+            break None;
+        }
+    }
+}
+
+pub fn follow_path_no_track(mut guard: Guard, map: &Map) -> Option<()> {
+    let mut bonked: HashSet<(Position, Direction)> = HashSet::new();
+
+    loop {
+        let pos = guard.position;
+
+        if pos.x < 0 || pos.x >= map.width || pos.y < 0 || pos.y >= map.height {
+            break None;
+        }
+
+        if guard.do_move(map) && !bonked.insert((guard.position, guard.direction)) {
+            break Some(());
+        }
+    }
+}
+
+pub fn possible_obstructions(guard: Guard, mut map: Map, visited: &PositionSet) -> usize {
+    // We only try obstacles on visited tiles since those are the only that
+    // will have an effect on the route.
+    visited
+        .iter()
+        .filter(|pos| **pos != guard.position)
+        .filter_map(|pos| {
+            map.additional = Some(*pos);
+            follow_path_no_track(guard.clone(), &map)
+        })
+        .count()
+}
+
+pub struct Lab {
+    pub guard: Guard,
+    pub map: Map,
+    /// Positions on the guard's path out of the lab.
+    pub visited: PositionSet,
+}
+
+pub fn parse(input: &str) -> Result<Lab, AocError> {
+    let grid = Grid::try_from_text(input, |ch| {
+        (ch == '#' || ch == '.' || Direction::from_char(ch).is_ok()).then_some(ch)
+    })?;
+
+    if grid.height() == 0 {
+        return Err(AocError::InvalidInput("Empty grid".to_string()));
+    }
+
+    let too_large = |_| AocError::InvalidInput("Lab is too large".to_string());
+    let to_position = |position: Vector2d<usize>| position.try_components_into().map_err(too_large);
+
+    let obstacles = grid
+        .find_all(|&ch| ch == '#')
+        .into_iter()
+        .map(to_position)
+        .collect::<Result<PositionSet, AocError>>()?;
+
+    let guard = grid
+        .find_all(|&ch| Direction::from_char(ch).is_ok())
+        .pop()
+        .ok_or(AocError::InvalidInput("No guard found".to_string()))?;
+    let direction = Direction::from_char(grid[guard]).expect("Guard was found by its direction");
+
+    let map = Map {
+        width: grid.width().try_into().map_err(too_large)?,
+        height: grid.height().try_into().map_err(too_large)?,
+        obstacles,
+        additional: None,
+    };
+
+    let guard = Guard {
+        position: to_position(guard)?,
+        direction,
+    };
+
+    let visited = follow_path(guard.clone(), &map).ok_or(AocError::InvalidInput(
+        "Guard never leaves the lab".to_string(),
+    ))?;
+
+    Ok(Lab {
+        guard,
+        map,
+        visited,
+    })
+}
+
+/// Number of distinct positions visited by the guard.
+pub fn part1(lab: &Lab) -> usize {
+    lab.visited.len()
+}
+
+/// Number of positions where a new obstruction traps the guard in a loop.
+pub fn part2(lab: &Lab) -> usize {
+    possible_obstructions(lab.guard.clone(), lab.map.clone(), &lab.visited)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Lab;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(lab: &Self::Input) -> usize {
+        part1(lab)
+    }
+
+    fn part2(lab: &Self::Input) -> usize {
+        part2(lab)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input), 41);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input), 6);
+    }
+
+    #[test]
+    fn trapped_guard_is_invalid() {
+        let input = ".#...\n....#\n.....\n#^...\n...#.\n";
+        assert!(matches!(parse(input), Err(AocError::InvalidInput(_))));
+    }
+}
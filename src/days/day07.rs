@@ -0,0 +1,164 @@
+use std::iter;
+
+use crate::{
+    error::{parse_field, AocError},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Equation {
+    pub result: u64,
+    pub numbers: Vec<u64>,
+}
+
+pub fn parse_equation(line_no: usize, equation: &str) -> Result<Equation, AocError> {
+    let (result_field, numbers_field) = equation
+        .split_once(": ")
+        .ok_or_else(|| AocError::parse(line_no, 1, "Expected a line like 'X: A B ...'"))?;
+
+    let result: u64 = parse_field(line_no, equation, result_field)?;
+    let numbers: Vec<u64> = numbers_field
+        .split_whitespace()
+        .map(|num| parse_field(line_no, equation, num))
+        .collect::<Result<_, _>>()?;
+
+    if numbers.len() < 2 {
+        return Err(AocError::at(
+            line_no,
+            equation,
+            numbers_field,
+            "Expected at least two numbers",
+        ));
+    }
+
+    Ok(Equation { result, numbers })
+}
+
+pub fn parse_equations(input: &str) -> Result<Vec<Equation>, AocError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| parse_equation(idx + 1, line))
+        .collect()
+}
+
+pub fn concat(lhs: u64, rhs: u64) -> u64 {
+    let r_digits = rhs.checked_ilog10().unwrap_or(0) + 1;
+    lhs * 10u64.pow(r_digits) + rhs
+}
+
+pub fn possible_results<'val>(
+    values: &'val [u64],
+    with_concat: bool,
+) -> Box<dyn Iterator<Item = u64> + 'val> {
+    match values {
+        [l, r] => {
+            let sum = iter::once(l + r);
+            let mul = iter::once(l * r);
+
+            if with_concat {
+                let cat = iter::once(concat(*l, *r));
+                Box::new(sum.chain(mul).chain(cat))
+            } else {
+                Box::new(sum.chain(mul))
+            }
+        }
+
+        [prev @ .., r] => {
+            let sum_res = possible_results(prev, with_concat).map(move |res| res + r);
+            let mul_res = possible_results(prev, with_concat).map(move |res| res * r);
+
+            if with_concat {
+                let concat_res =
+                    possible_results(prev, with_concat).map(move |res| concat(res, *r));
+
+                Box::new(sum_res.chain(mul_res).chain(concat_res))
+            } else {
+                Box::new(sum_res.chain(mul_res))
+            }
+        }
+        _ => panic!("Value list contains less than 2 elements"),
+    }
+}
+
+pub fn validate_equation(equation: &Equation, with_concat: bool) -> bool {
+    possible_results(&equation.numbers, with_concat).any(|n| n == equation.result)
+}
+
+pub fn sum_valid(equations: &[Equation], with_concat: bool) -> u64 {
+    equations
+        .iter()
+        .filter_map(|eq| {
+            if validate_equation(eq, with_concat) {
+                Some(eq.result)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Equation>, AocError> {
+    parse_equations(input)
+}
+
+/// Calibration result using only addition and multiplication.
+pub fn part1(equations: &[Equation]) -> u64 {
+    sum_valid(equations, false)
+}
+
+/// Calibration result with the concatenation operator.
+pub fn part2(equations: &[Equation]) -> u64 {
+    sum_valid(equations, true)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Equation>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(equations: &Self::Input) -> u64 {
+        part1(equations)
+    }
+
+    fn part2(equations: &Self::Input) -> u64 {
+        part2(equations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input), 3749);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input), 11387);
+    }
+}
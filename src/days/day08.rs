@@ -0,0 +1,155 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+
+use crate::{
+    error::AocError,
+    geometry::lattice_line,
+    grid::{Bounds, Grid},
+    solution::Solution,
+    vector::Vector2d,
+};
+
+pub type AntennaIndex = HashMap<char, Vec<Vector2d<usize>>>;
+pub type DeltaList = Vec<(Vector2d<usize>, Vector2d<isize>)>;
+
+pub struct Map {
+    pub width: usize,
+    pub height: usize,
+    pub index: AntennaIndex,
+}
+
+pub fn parse_map(grid: &Grid<char>) -> AntennaIndex {
+    grid.index_by(|&ch| (ch != '.').then_some(ch))
+}
+
+pub fn in_bound(vector: Vector2d<usize>, width: usize, height: usize) -> bool {
+    vector.x < width && vector.y < height
+}
+
+pub fn find_deltas(map: &Map) -> DeltaList {
+    map.index
+        .values()
+        .flat_map(|antennas| antennas.iter().combinations(2))
+        .filter_map(|pair| Some((*pair[0], pair[1].checked_signed_diff(*pair[0])?)))
+        .collect()
+}
+
+pub fn find_antinodes(map: &Map, deltas: &DeltaList) -> HashSet<Vector2d<usize>> {
+    deltas
+        .iter()
+        .flat_map(|(base, delta)| {
+            [
+                base.checked_add_signed(-*delta),
+                base.checked_add_signed(delta.scalar_mul(2)),
+            ]
+        })
+        .flatten()
+        .filter(|antinode| in_bound(*antinode, map.width, map.height))
+        .collect()
+}
+
+/// Every position in line with at least two antennas of the same frequency.
+/// When the offset between two antennas has a common factor, the line
+/// also passes through positions between the multiples of that offset.
+pub fn find_resonant_antinodes(map: &Map, deltas: &DeltaList) -> HashSet<Vector2d<usize>> {
+    let Some(bounds) = Bounds::of_size(map.width, map.height) else {
+        return HashSet::new();
+    };
+
+    deltas
+        .iter()
+        .filter_map(|&(base, delta)| Some((base.try_components_into().ok()?, delta)))
+        .flat_map(|(base, delta)| lattice_line(base, delta, bounds))
+        .filter_map(|antinode| antinode.try_components_into().ok())
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<Map, AocError> {
+    let grid = Grid::from_text(input, |ch| ch)?;
+
+    if grid.height() == 0 {
+        return Err(AocError::InvalidInput("Empty grid".to_string()));
+    }
+
+    Ok(Map {
+        width: grid.width(),
+        height: grid.height(),
+        index: parse_map(&grid),
+    })
+}
+
+/// Number of unique antinode locations.
+pub fn part1(map: &Map) -> usize {
+    let deltas = find_deltas(map);
+
+    find_antinodes(map, &deltas).len()
+}
+
+/// Number of unique antinode locations with resonant harmonics.
+pub fn part2(map: &Map) -> usize {
+    let deltas = find_deltas(map);
+
+    find_resonant_antinodes(map, &deltas).len()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Input) -> usize {
+        part1(map)
+    }
+
+    fn part2(map: &Self::Input) -> usize {
+        part2(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input), 14);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input), 34);
+    }
+
+    #[test]
+    fn resonance_between_distant_antennas() {
+        // The antennas are two rows apart, so the middle row is in line too
+        let input = parse("a....\n.....\na....\n").unwrap();
+        assert_eq!(part2(&input), 3);
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::VecDeque;
+
+use crate::{error::AocError, solution::Solution};
+
+#[derive(Debug, Clone)]
+pub struct File {
+    pub id: usize,
+    pub blocks: VecDeque<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DiskMap {
+    pub files: Vec<File>,
+    /// Free space spans as `(start, length)` pairs.
+    pub free: VecDeque<(usize, usize)>,
+}
+
+impl File {
+    pub fn from_offset(id: usize, offset: usize, length: u8) -> Self {
+        Self {
+            id,
+            blocks: VecDeque::from_iter((offset..).take(length as usize)),
+        }
+    }
+
+    pub fn contiguous_span(&mut self) -> Result<(usize, usize), &str> {
+        let contig = self.blocks.make_contiguous();
+
+        if !contig.windows(2).all(|pair| pair[0].abs_diff(pair[1]) == 1) {
+            Err("Non contiguous file")
+        } else {
+            let start = contig[0];
+            let length = contig.len();
+            Ok((start, length))
+        }
+    }
+
+    pub fn move_to_contiguous(&mut self, start: usize) {
+        let len = self.blocks.len();
+        self.blocks.clear();
+        self.blocks.extend((start..).take(len));
+    }
+}
+
+pub fn parse_disk_map(disk: &str) -> Result<DiskMap, AocError> {
+    if let Some((column, ch)) = disk
+        .chars()
+        .enumerate()
+        .find(|(_, ch)| !ch.is_ascii_digit())
+    {
+        return Err(AocError::parse(
+            1,
+            column + 1,
+            format!("Expected a digit, found {ch:?}"),
+        ));
+    }
+
+    let mut files: Vec<File> = Vec::new();
+    let mut free: VecDeque<(usize, usize)> = VecDeque::new();
+
+    let mut offset: usize = 0;
+
+    for (idx, pair) in disk.as_bytes().chunks(2).enumerate() {
+        let file_length: u8 = pair.first().map(|n| n - 0x30).expect("File in chunk");
+        let file = File::from_offset(idx, offset, file_length);
+
+        if file_length > 0 {
+            files.push(file);
+            offset += file_length as usize;
+        }
+
+        let Some(free_length) = pair.get(1).map(|n| n - 0x30) else {
+            break;
+        };
+
+        if free_length > 0 {
+            free.push_back((offset, free_length as usize));
+            offset += free_length as usize;
+        }
+    }
+
+    Ok(DiskMap { files, free })
+}
+
+pub fn into_free_blocks(free_contiguous: VecDeque<(usize, usize)>) -> VecDeque<usize> {
+    VecDeque::from_iter(
+        free_contiguous
+            .iter()
+            .flat_map(|(start, length)| (*start..).take(*length)),
+    )
+}
+
+pub fn checksum(files: &[File]) -> usize {
+    files
+        .iter()
+        .map(|file| {
+            file.blocks
+                .iter()
+                .map(|block| block * file.id)
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+pub fn defrag_check_blocks(disk_map: &DiskMap) -> usize {
+    let mut files = disk_map.files.clone();
+    let mut free = into_free_blocks(disk_map.free.clone());
+
+    'outer: for file in files.iter_mut().rev() {
+        for _ in 0..file.blocks.len() {
+            let Some(new_block) = free.pop_front() else {
+                break 'outer;
+            };
+
+            if new_block > file.blocks.back().cloned().unwrap_or(0) {
+                break 'outer;
+            }
+
+            file.blocks.pop_back();
+            file.blocks.push_front(new_block);
+        }
+    }
+
+    checksum(&files)
+}
+
+pub fn defrag_check_contiguous(disk_map: &DiskMap) -> usize {
+    let mut files = disk_map.files.clone();
+    let mut free = disk_map.free.clone();
+
+    for file in files.iter_mut().rev() {
+        let contig = file.contiguous_span().expect("Contiguous file");
+
+        let Some(free_idx) = free.iter().position(|f| f.0 < contig.0 && f.1 >= contig.1) else {
+            continue;
+        };
+
+        let target = free[free_idx];
+
+        file.move_to_contiguous(target.0);
+
+        let new_free = (target.0 + contig.1, target.1 - contig.1);
+
+        if new_free.1 == 0 {
+            free.remove(free_idx);
+        } else {
+            free[free_idx] = new_free;
+        }
+    }
+
+    checksum(&files)
+}
+
+pub fn parse(input: &str) -> Result<DiskMap, AocError> {
+    parse_disk_map(input.trim_end())
+}
+
+/// Filesystem checksum after compacting block by block.
+pub fn part1(disk_map: &DiskMap) -> usize {
+    defrag_check_blocks(disk_map)
+}
+
+/// Filesystem checksum after compacting whole files.
+pub fn part2(disk_map: &DiskMap) -> usize {
+    defrag_check_contiguous(disk_map)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = DiskMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(disk_map: &Self::Input) -> usize {
+        part1(disk_map)
+    }
+
+    fn part2(disk_map: &Self::Input) -> usize {
+        part2(disk_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2333133121414131402
+";
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input), 1928);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input), 2858);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{error::AocError, grid::Grid, search::bfs, solution::Solution, vector::Vector2d};
+
+/// Score and rating of the trailhead at `position`. Every step climbs exactly
+/// one level, so all paths to a given top are shortest paths and the rating
+/// is the sum of their counts.
+pub fn find_trails(grid: &Grid<u8>, position: Vector2d<usize>) -> (usize, usize) {
+    let trails = bfs(position, |&position| {
+        let height = grid[position];
+
+        grid.neighbors4(position)
+            .filter(move |(_, _, next_slope)| height.checked_add(1) == Some(**next_slope))
+            .map(|(_, new_pos, _)| new_pos)
+    });
+
+    trails
+        .path_counts
+        .iter()
+        .filter(|(position, _)| grid[**position] == 9)
+        .fold((0, 0), |(score, rating), (_, count)| {
+            (score + 1, rating + count)
+        })
+}
+
+pub fn count_trailheads(grid: &Grid<u8>) -> (usize, usize) {
+    let (scores, ratings) = grid
+        .find_all(|&height| height == 0)
+        .into_iter()
+        .map(|position| find_trails(grid, position))
+        .fold((0, 0), |(score_acc, rating_acc), (score, rating)| {
+            (score_acc + score, rating_acc + rating)
+        });
+
+    (scores, ratings)
+}
+
+pub fn parse(input: &str) -> Result<Grid<u8>, AocError> {
+    Grid::try_from_text(input, |ch| ch.to_digit(10).map(|digit| digit as u8))
+}
+
+/// Sum of the scores of all trailheads.
+pub fn part1(grid: &Grid<u8>) -> usize {
+    count_trailheads(grid).0
+}
+
+/// Sum of the ratings of all trailheads.
+pub fn part2(grid: &Grid<u8>) -> usize {
+    count_trailheads(grid).1
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        part1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        part2(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input), 36);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input), 81);
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use crate::{
+    error::{parse_field, AocError},
+    solution::Solution,
+};
+
+pub fn has_even_digits(number: u64) -> bool {
+    (number.ilog10() + 1).is_multiple_of(2)
+}
+
+pub fn split_number(number: u64) -> (u64, u64) {
+    let n_digits = number.ilog10() + 1;
+    let denominator = 10u64.pow(n_digits / 2);
+
+    (number / denominator, number % denominator)
+}
+
+fn increment_stones(stone_count: &mut HashMap<u64, usize>, number: u64, count: usize) {
+    *stone_count.entry(number).or_default() += count
+}
+
+pub fn simulate_stones(stones: &[u64], blinks: usize) -> usize {
+    let mut stone_count: HashMap<u64, usize> =
+        stones
+            .iter()
+            .copied()
+            .fold(HashMap::new(), |mut acc, stone| {
+                *acc.entry(stone).or_default() += 1;
+                acc
+            });
+
+    for _ in 0..blinks {
+        let mut new_stones: HashMap<u64, usize> = HashMap::new();
+
+        for (stone, count) in stone_count {
+            match stone {
+                0 => increment_stones(&mut new_stones, 1, count),
+                n if has_even_digits(n) => {
+                    let (l, r) = split_number(n);
+                    increment_stones(&mut new_stones, l, count);
+                    increment_stones(&mut new_stones, r, count);
+                }
+                n => increment_stones(&mut new_stones, n * 2024, count),
+            }
+        }
+
+        stone_count = new_stones;
+    }
+
+    stone_count.values().sum()
+}
+
+pub fn parse(input: &str) -> Result<Vec<u64>, AocError> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(idx, line)| {
+            line.split_whitespace()
+                .map(move |stone| parse_field(idx + 1, line, stone))
+        })
+        .collect()
+}
+
+/// Number of stones after blinking 25 times.
+pub fn part1(stones: &[u64]) -> usize {
+    simulate_stones(stones, 25)
+}
+
+/// Number of stones after blinking 75 times.
+pub fn part2(stones: &[u64]) -> usize {
+    simulate_stones(stones, 75)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<u64>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(stones: &Self::Input) -> usize {
+        part1(stones)
+    }
+
+    fn part2(stones: &Self::Input) -> usize {
+        part2(stones)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "125 17\n";
+
+    #[test]
+    fn example_short() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(simulate_stones(&input, 6), 22);
+    }
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&input), 55312);
+    }
+
+    #[test]
+    fn example_part2() {
+        // The puzzle doesn't publish this one, it's kept as a regression check
+        let input = parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&input), 65601038650482);
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{
+    error::AocError,
+    solution::{solve, Answers},
+};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+
+pub type Solver = fn(&str) -> Result<Answers, AocError>;
+
+const SOLVERS: [Solver; 11] = [
+    solve::<day01::Day01>,
+    solve::<day02::Day02>,
+    solve::<day03::Day03>,
+    solve::<day04::Day04>,
+    solve::<day05::Day05>,
+    solve::<day06::Day06>,
+    solve::<day07::Day07>,
+    solve::<day08::Day08>,
+    solve::<day09::Day09>,
+    solve::<day10::Day10>,
+    solve::<day11::Day11>,
+];
+
+/// Returns the solver for `day`, counting days from 1.
+pub fn solver(day: u8) -> Option<Solver> {
+    let idx = usize::from(day).checked_sub(1)?;
+    SOLVERS.get(idx).copied()
+}
+
+/// Every day that has a solver, in order.
+pub fn available() -> impl Iterator<Item = u8> {
+    1..=SOLVERS.len() as u8
+}
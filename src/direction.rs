@@ -5,6 +5,7 @@ use num_traits::Num;
 use crate::vector::Vector2d;
 
 pub trait AsVector {
+    #[allow(clippy::wrong_self_convention)]
     fn as_vector<T: Num + Neg<Output = T>>(self) -> Vector2d<T>;
 }
 
@@ -17,6 +18,14 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
     pub fn from_char(ch: char) -> Result<Self, &'static str> {
         use Direction::*;
         Ok(match ch {
@@ -72,6 +81,20 @@ pub enum Direction8 {
     NorthWest,
 }
 
+impl Direction8 {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+}
+
 impl AsVector for Direction8 {
     fn as_vector<T: Num + Neg<Output = T>>(self) -> Vector2d<T> {
         match self {
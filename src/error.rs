@@ -0,0 +1,75 @@
+use std::{error::Error, fmt, io, str::FromStr};
+
+#[derive(Debug)]
+pub enum AocError {
+    /// A required command line argument was not given.
+    MissingArgument(&'static str),
+    /// The input could not be read.
+    Io { path: String, source: io::Error },
+    /// The input is malformed at a specific position. Lines and columns
+    /// start at 1.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is malformed as a whole, e.g. a required element is
+    /// missing.
+    InvalidInput(String),
+}
+
+impl AocError {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Builds a parse error pointing at `field`, which must be a slice of
+    /// `line`.
+    pub fn at(line_no: usize, line: &str, field: &str, message: impl Into<String>) -> Self {
+        AocError::parse(line_no, column_of(line, field), message)
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::MissingArgument(name) => write!(f, "Missing argument: {name}"),
+            AocError::Io { path, source } => write!(f, "Could not read {path}: {source}"),
+            AocError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "Parse error at line {line}, column {column}: {message}"),
+            AocError::InvalidInput(message) => write!(f, "Invalid input: {message}"),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// 1-based column where `field` starts inside `line`. `field` must be a
+/// slice of `line`, as returned by `split` and friends.
+pub fn column_of(line: &str, field: &str) -> usize {
+    let offset = (field.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+    line.get(..offset)
+        .map_or(0, |prefix| prefix.chars().count())
+        + 1
+}
+
+/// Parses `field`, a slice of `line`, reporting its position on failure.
+pub fn parse_field<T: FromStr>(line_no: usize, line: &str, field: &str) -> Result<T, AocError> {
+    field
+        .parse()
+        .map_err(|_| AocError::at(line_no, line, field, format!("Invalid number {field:?}")))
+}
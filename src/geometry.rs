@@ -0,0 +1,287 @@
+//! Geometry on the integer lattice, built on `Vector2d`.
+
+use num_traits::Signed;
+
+use crate::grid::Bounds;
+use crate::vector::{gcd, Vector2d};
+
+/// Range of `t` for which `start + t * step` lies within `min..=max`.
+fn steps_within(start: isize, step: isize, min: isize, max: isize) -> Option<(isize, isize)> {
+    // Rounds towards negative and positive infinity respectively
+    let floor_div =
+        |a: isize, b: isize| a.div_euclid(b) - isize::from(b < 0 && a.rem_euclid(b) != 0);
+    let ceil_div = |a: isize, b: isize| -floor_div(-a, b);
+
+    match step.signum() {
+        0 => (min..=max)
+            .contains(&start)
+            .then_some((isize::MIN, isize::MAX)),
+        1 => Some((ceil_div(min - start, step), floor_div(max - start, step))),
+        _ => Some((ceil_div(max - start, step), floor_div(min - start, step))),
+    }
+}
+
+/// Every lattice point within `bounds` on the line through `point` with the
+/// given direction, in order along `direction`. The direction is reduced by
+/// the gcd of its components first, so no point in between is skipped. A
+/// zero direction gives just `point`, if it's within bounds.
+pub fn lattice_line(
+    point: Vector2d<isize>,
+    direction: Vector2d<isize>,
+    bounds: Bounds,
+) -> impl Iterator<Item = Vector2d<isize>> {
+    let step = direction.reduced();
+
+    let range = if step == Vector2d::new(0, 0) {
+        bounds.contains(point).then_some((0, 0))
+    } else {
+        steps_within(point.x, step.x, bounds.min.x, bounds.max.x).and_then(|(x_lo, x_hi)| {
+            let (y_lo, y_hi) = steps_within(point.y, step.y, bounds.min.y, bounds.max.y)?;
+            Some((x_lo.max(y_lo), x_hi.min(y_hi)))
+        })
+    };
+
+    let (first, last) = range.unwrap_or((1, 0));
+    (first..=last).map(move |t| point + step.scalar_mul(t))
+}
+
+/// Points of the segment from `from` to `to`, both included, as drawn by
+/// Bresenham's algorithm.
+pub fn bresenham(from: Vector2d<isize>, to: Vector2d<isize>) -> Vec<Vector2d<isize>> {
+    let delta = (to - from).abs();
+    let step = (to - from).signum();
+    let mut error = delta.x - delta.y;
+    let mut current = from;
+    let mut points = vec![current];
+
+    while current != to {
+        let doubled = 2 * error;
+
+        if doubled > -delta.y {
+            error -= delta.y;
+            current.x += step.x;
+        }
+        if doubled < delta.x {
+            error += delta.x;
+            current.y += step.y;
+        }
+
+        points.push(current);
+    }
+
+    points
+}
+
+/// Pairs of consecutive vertices, including the edge closing the polygon.
+fn edges<T: Copy>(
+    vertices: &[Vector2d<T>],
+) -> impl Iterator<Item = (Vector2d<T>, Vector2d<T>)> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+}
+
+/// Twice the area of the polygon, by the shoelace formula. The vertices can
+/// go in either direction, and the polygon is closed implicitly. Doubling
+/// keeps the result exact, since lattice polygons can have half units of
+/// area.
+pub fn double_area<T: Signed + Copy + PartialOrd>(vertices: &[Vector2d<T>]) -> T {
+    edges(vertices)
+        .fold(T::zero(), |total, (a, b)| total + a.cross(b))
+        .abs()
+}
+
+/// Number of lattice points on the edges of the polygon.
+pub fn boundary_points<T: Signed + Copy>(vertices: &[Vector2d<T>]) -> T {
+    edges(vertices).fold(T::zero(), |total, (a, b)| {
+        let delta = b - a;
+        total + gcd(delta.x, delta.y)
+    })
+}
+
+/// Number of lattice points strictly inside the polygon, by Pick's theorem.
+/// With a path of unit steps as the vertices, these are the tiles enclosed
+/// by the loop.
+pub fn interior_points<T: Signed + Copy + PartialOrd>(vertices: &[Vector2d<T>]) -> T {
+    let two = T::one() + T::one();
+    (double_area(vertices) - boundary_points(vertices) + two) / two
+}
+
+/// Whether `point` is inside the polygon or on its edges, by its winding
+/// number.
+pub fn point_in_polygon<T: Signed + Copy + PartialOrd>(
+    point: Vector2d<T>,
+    vertices: &[Vector2d<T>],
+) -> bool {
+    let mut winding = 0;
+
+    for (a, b) in edges(vertices) {
+        let side = (b - a).cross(point - a);
+
+        let within = |p: T, q: T, r: T| (p <= r && r <= q) || (q <= r && r <= p);
+        if side.is_zero() && within(a.x, b.x, point.x) && within(a.y, b.y, point.y) {
+            return true;
+        }
+
+        if a.y <= point.y && b.y > point.y && side.is_positive() {
+            winding += 1;
+        } else if a.y > point.y && b.y <= point.y && side.is_negative() {
+            winding -= 1;
+        }
+    }
+
+    winding != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds(width: usize, height: usize) -> Bounds {
+        Bounds::of_size(width, height).unwrap()
+    }
+
+    #[test]
+    fn lattice_line_covers_bounds() {
+        let line: Vec<_> =
+            lattice_line(Vector2d::new(3, 3), Vector2d::new(4, 2), bounds(10, 10)).collect();
+
+        assert_eq!(
+            line,
+            vec![
+                Vector2d::new(1, 2),
+                Vector2d::new(3, 3),
+                Vector2d::new(5, 4),
+                Vector2d::new(7, 5),
+                Vector2d::new(9, 6),
+            ]
+        );
+
+        let reversed: Vec<_> =
+            lattice_line(Vector2d::new(3, 3), Vector2d::new(-4, -2), bounds(10, 10)).collect();
+        assert_eq!(reversed.first(), Some(&Vector2d::new(9, 6)));
+        assert_eq!(reversed.len(), 5);
+    }
+
+    #[test]
+    fn lattice_line_edge_cases() {
+        let vertical: Vec<_> =
+            lattice_line(Vector2d::new(2, 1), Vector2d::new(0, -5), bounds(4, 3)).collect();
+        assert_eq!(
+            vertical,
+            vec![
+                Vector2d::new(2, 2),
+                Vector2d::new(2, 1),
+                Vector2d::new(2, 0)
+            ]
+        );
+
+        // Starting outside the bounds, but crossing them
+        assert_eq!(
+            lattice_line(Vector2d::new(-2, -2), Vector2d::new(1, 1), bounds(3, 3)).count(),
+            3
+        );
+        assert_eq!(
+            lattice_line(Vector2d::new(-2, 0), Vector2d::new(0, 1), bounds(3, 3)).count(),
+            0
+        );
+        assert_eq!(
+            lattice_line(Vector2d::new(1, 1), Vector2d::new(0, 0), bounds(3, 3)).count(),
+            1
+        );
+    }
+
+    #[test]
+    fn bresenham_segments() {
+        assert_eq!(
+            bresenham(Vector2d::new(0, 0), Vector2d::new(4, 2)),
+            vec![
+                Vector2d::new(0, 0),
+                Vector2d::new(1, 0),
+                Vector2d::new(2, 1),
+                Vector2d::new(3, 1),
+                Vector2d::new(4, 2),
+            ]
+        );
+        assert_eq!(bresenham(Vector2d::new(2, 3), Vector2d::new(2, 0)).len(), 4);
+        assert_eq!(
+            bresenham(Vector2d::new(1, 1), Vector2d::new(1, 1)),
+            vec![Vector2d::new(1, 1)]
+        );
+
+        let steep = bresenham(Vector2d::new(0, 0), Vector2d::new(-2, -5));
+        assert_eq!(steep.len(), 6);
+        assert!(steep
+            .windows(2)
+            .all(|pair| pair[0].chebyshev_distance(pair[1]) == 1));
+    }
+
+    #[test]
+    fn area_and_pick() {
+        // 4x3 rectangle, listed clockwise
+        let rectangle = [
+            Vector2d::new(0_i64, 0),
+            Vector2d::new(4, 0),
+            Vector2d::new(4, 3),
+            Vector2d::new(0, 3),
+        ];
+        assert_eq!(double_area(&rectangle), 24);
+        assert_eq!(boundary_points(&rectangle), 14);
+        assert_eq!(interior_points(&rectangle), 6);
+
+        let triangle = [
+            Vector2d::new(0_i64, 0),
+            Vector2d::new(3, 1),
+            Vector2d::new(1, 2),
+        ];
+        assert_eq!(double_area(&triangle), 5);
+        assert_eq!(interior_points(&triangle), 2);
+    }
+
+    #[test]
+    fn points_in_loop() {
+        // An L shaped loop walked one tile at a time
+        let path: Vec<Vector2d<i32>> = [
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (2, 1),
+            (2, 2),
+            (3, 2),
+            (4, 2),
+            (4, 3),
+            (4, 4),
+            (3, 4),
+            (2, 4),
+            (1, 4),
+            (0, 4),
+            (0, 3),
+            (0, 2),
+            (0, 1),
+        ]
+        .into_iter()
+        .map(|(x, y)| Vector2d::new(x, y))
+        .collect();
+
+        assert_eq!(interior_points(&path), 5);
+
+        let inside: Vec<_> = (0..5)
+            .flat_map(|y| (0..5).map(move |x| Vector2d::new(x, y)))
+            .filter(|point| !path.contains(point) && point_in_polygon(*point, &path))
+            .collect();
+        assert_eq!(
+            inside,
+            vec![
+                Vector2d::new(1, 1),
+                Vector2d::new(1, 2),
+                Vector2d::new(1, 3),
+                Vector2d::new(2, 3),
+                Vector2d::new(3, 3)
+            ]
+        );
+        assert!(point_in_polygon(Vector2d::new(4, 3), &path));
+        assert!(!point_in_polygon(Vector2d::new(3, 1), &path));
+        assert!(!point_in_polygon(Vector2d::new(5, 2), &path));
+    }
+}
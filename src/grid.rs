@@ -1,36 +1,267 @@
-use std::slice::Iter;
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt,
+    hash::Hash,
+    ops::{Index, IndexMut},
+    slice::{ChunksExact, Iter},
+};
 
+use crate::direction::{AsVector, Direction, Direction8};
+use crate::error::AocError;
 use crate::vector::Vector2d;
 
+/// A position that doesn't fit in a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfBounds {
+    pub position: Vector2d<usize>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl fmt::Display for OutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Position {} is outside of the {}x{} grid",
+            self.position, self.width, self.height
+        )
+    }
+}
+
+impl Error for OutOfBounds {}
+
+/// Inclusive rectangle of signed positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Vector2d<isize>,
+    pub max: Vector2d<isize>,
+}
+
+impl Bounds {
+    /// Bounds covering a single position.
+    pub fn at(position: Vector2d<isize>) -> Self {
+        Self {
+            min: position,
+            max: position,
+        }
+    }
+
+    /// Bounds of a `width` by `height` area starting at the origin, if it
+    /// isn't empty.
+    pub fn of_size(width: usize, height: usize) -> Option<Self> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        Some(Self {
+            min: Vector2d::new(0, 0),
+            max: Vector2d::new(
+                isize::try_from(width - 1).ok()?,
+                isize::try_from(height - 1).ok()?,
+            ),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.min.x.abs_diff(self.max.x) + 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.min.y.abs_diff(self.max.y) + 1
+    }
+
+    pub fn contains(&self, position: Vector2d<isize>) -> bool {
+        (self.min.x..=self.max.x).contains(&position.x)
+            && (self.min.y..=self.max.y).contains(&position.y)
+    }
+
+    /// Smallest bounds containing these and `position`.
+    pub fn including(self, position: Vector2d<isize>) -> Self {
+        Self {
+            min: Vector2d::new(self.min.x.min(position.x), self.min.y.min(position.y)),
+            max: Vector2d::new(self.max.x.max(position.x), self.max.y.max(position.y)),
+        }
+    }
+}
+
+/// Lookups shared by the dense `Grid` and the `SparseGrid`, addressed by
+/// signed positions so algorithms can accept either.
+pub trait GridLike {
+    type Item;
+
+    /// Item at `position`, `None` if it's out of bounds or empty.
+    fn get_signed(&self, position: Vector2d<isize>) -> Option<&Self::Item>;
+
+    /// Whether `position` is inside the area the grid covers, which may be
+    /// unlimited.
+    fn in_bounds(&self, position: Vector2d<isize>) -> bool;
+
+    /// Smallest bounds containing every item, `None` if there are none.
+    fn bounding_box(&self) -> Option<Bounds>;
+
+    /// Every stored item along with its position.
+    fn items(&self) -> impl Iterator<Item = (Vector2d<isize>, &Self::Item)>;
+
+    /// Orthogonal neighbors of `position` that hold an item.
+    fn neighbors4(
+        &self,
+        position: Vector2d<isize>,
+    ) -> impl Iterator<Item = (Direction, Vector2d<isize>, &Self::Item)> {
+        Direction::ALL.into_iter().filter_map(move |direction| {
+            let neighbor = position + direction.as_vector();
+            let item = self.get_signed(neighbor)?;
+            Some((direction, neighbor, item))
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Grid<T> {
     width: usize,
     height: usize,
     data: Vec<T>,
 }
 
-impl<T: Default + Clone> Grid<T> {
-    pub fn new(width: usize, height: usize) -> Self {
-        Self {
-            width,
-            height,
-            data: vec![T::default(); width * height],
+/// Reads grids from puzzle text, one row per line. Lines may end with `\n`
+/// or `\r\n`, and blank lines at the end are ignored. Every other line
+/// must have the same number of characters.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GridParser {
+    trim_trailing_whitespace: bool,
+}
+
+impl GridParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ignore whitespace at the end of every line, for inputs that were
+    /// padded by an editor.
+    pub fn trim_trailing_whitespace(mut self, trim: bool) -> Self {
+        self.trim_trailing_whitespace = trim;
+        self
+    }
+
+    /// Lines that make up the grid, without line endings or the trailing
+    /// blank lines.
+    fn lines<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        let mut lines: Vec<&str> = text
+            .split('\n')
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+            .map(|line| {
+                if self.trim_trailing_whitespace {
+                    line.trim_end()
+                } else {
+                    line
+                }
+            })
+            .collect();
+
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+
+        lines
+    }
+
+    fn check_width(line_no: usize, width: usize, line_width: usize) -> Result<(), AocError> {
+        if line_width == width {
+            Ok(())
+        } else {
+            Err(AocError::parse(
+                line_no,
+                line_width.min(width) + 1,
+                format!("Expected {width} columns, found {line_width}"),
+            ))
         }
     }
 
-    pub fn from_text(text: &str, converter: fn(char) -> T) -> Self {
-        let width = text.lines().next().unwrap().len();
-        let height = text.lines().count();
+    /// Converts every character with `converter`, which may reject it by
+    /// returning `None`.
+    pub fn parse<T>(
+        &self,
+        text: &str,
+        mut converter: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, AocError> {
+        let lines = self.lines(text);
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let mut data: Vec<T> = Vec::with_capacity(width * lines.len());
 
-        let data: Vec<T> = Vec::from_iter(text.chars().filter(|ch| *ch != '\n').map(converter));
+        for (idx, line) in lines.iter().enumerate() {
+            let mut line_width = 0;
+
+            for (column, ch) in line.chars().enumerate() {
+                let item = converter(ch).ok_or_else(|| {
+                    AocError::parse(idx + 1, column + 1, format!("Unexpected character {ch:?}"))
+                })?;
+                data.push(item);
+                line_width += 1;
+            }
+
+            Self::check_width(idx + 1, width, line_width)?;
+        }
+
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            data,
+        })
+    }
+
+    /// Keeps the raw bytes of an ASCII grid, skipping the per character
+    /// conversion.
+    pub fn parse_bytes(&self, text: &str) -> Result<Grid<u8>, AocError> {
+        let lines = self.lines(text);
+        let width = lines.first().map_or(0, |line| line.len());
+        let mut data: Vec<u8> = Vec::with_capacity(width * lines.len());
+
+        for (idx, line) in lines.iter().enumerate() {
+            if let Some(column) = line.bytes().position(|byte| !byte.is_ascii()) {
+                let ch = line[column..].chars().next().unwrap_or_default();
+                return Err(AocError::parse(
+                    idx + 1,
+                    line[..column].chars().count() + 1,
+                    format!("Unexpected character {ch:?}"),
+                ));
+            }
+
+            Self::check_width(idx + 1, width, line.len())?;
+            data.extend_from_slice(line.as_bytes());
+        }
 
-        assert_eq!(width * height, data.len());
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            data,
+        })
+    }
+}
 
+impl<T: Default + Clone> Grid<T> {
+    pub fn new(width: usize, height: usize) -> Self {
         Self {
             width,
             height,
-            data,
+            data: vec![T::default(); width * height],
         }
     }
+}
+
+impl<T> Grid<T> {
+    /// Parses a grid with the default `GridParser`.
+    pub fn from_text(text: &str, mut converter: impl FnMut(char) -> T) -> Result<Self, AocError> {
+        GridParser::new().parse(text, |ch| Some(converter(ch)))
+    }
+
+    /// Like `from_text`, but the converter may reject characters by
+    /// returning `None`.
+    pub fn try_from_text(
+        text: &str,
+        converter: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, AocError> {
+        GridParser::new().parse(text, converter)
+    }
 
     pub fn width(&self) -> usize {
         self.width
@@ -40,35 +271,1027 @@ impl<T: Default + Clone> Grid<T> {
         self.height
     }
 
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         self.data.iter()
     }
 
+    /// Grid of the same size with every item converted by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+
     pub fn item_indices(&self) -> impl Iterator<Item = (Vector2d<usize>, &T)> {
         self.data
             .iter()
             .enumerate()
-            .map(|(idx, item)| (Vector2d::new(idx % self.width, idx / self.height), item))
+            .map(|(idx, item)| (Vector2d::new(idx % self.width, idx / self.width), item))
+    }
+
+    fn index_of(&self, position: Vector2d<usize>) -> Option<usize> {
+        self.has_position(position)
+            .then(|| position.y * self.width + position.x)
     }
 
     pub fn get(&self, x: usize, y: usize) -> Option<&T> {
-        if x > self.width - 1 || y > self.height - 1 {
-            None
+        self.get_at(Vector2d::new(x, y))
+    }
+
+    pub fn get_at(&self, position: Vector2d<usize>) -> Option<&T> {
+        self.index_of(position).map(|idx| &self.data[idx])
+    }
+
+    pub fn get_mut(&mut self, position: Vector2d<usize>) -> Option<&mut T> {
+        self.index_of(position).map(|idx| &mut self.data[idx])
+    }
+
+    /// Lookup by signed coordinates, where anything negative is simply
+    /// outside the grid.
+    pub fn get_signed(&self, position: Vector2d<isize>) -> Option<&T> {
+        let x = usize::try_from(position.x).ok()?;
+        let y = usize::try_from(position.y).ok()?;
+        self.get_at(Vector2d::new(x, y))
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> Result<(), OutOfBounds> {
+        let position = Vector2d::new(x, y);
+        let (width, height) = (self.width, self.height);
+
+        let item = self.get_mut(position).ok_or(OutOfBounds {
+            position,
+            width,
+            height,
+        })?;
+        *item = value;
+        Ok(())
+    }
+
+    /// Position of the first item equal to `value`, in row order.
+    pub fn find(&self, value: &T) -> Option<Vector2d<usize>>
+    where
+        T: PartialEq,
+    {
+        self.item_indices()
+            .find(|(_, item)| *item == value)
+            .map(|(position, _)| position)
+    }
+
+    /// Positions of every item matching `predicate`, in row order.
+    pub fn find_all(&self, mut predicate: impl FnMut(&T) -> bool) -> Vec<Vector2d<usize>> {
+        self.item_indices()
+            .filter(|(_, item)| predicate(item))
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    /// Groups positions by the key `key_fn` gives to their items, skipping
+    /// the items it returns `None` for. Positions keep their row order.
+    pub fn index_by<K: Eq + Hash>(
+        &self,
+        mut key_fn: impl FnMut(&T) -> Option<K>,
+    ) -> HashMap<K, Vec<Vector2d<usize>>> {
+        let mut index: HashMap<K, Vec<Vector2d<usize>>> = HashMap::new();
+
+        for (position, item) in self.item_indices() {
+            if let Some(key) = key_fn(item) {
+                index.entry(key).or_default().push(position);
+            }
+        }
+
+        index
+    }
+
+    pub fn has_position(&self, position: Vector2d<usize>) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    /// Position `n` steps away from `position` towards `direction`, if it
+    /// is inside the grid.
+    pub fn step<D: AsVector>(
+        &self,
+        position: Vector2d<usize>,
+        direction: D,
+        n: usize,
+    ) -> Option<Vector2d<usize>> {
+        let new_position = position.checked_offset(direction, n)?;
+        self.has_position(new_position).then_some(new_position)
+    }
+
+    /// Rows of the grid, top to bottom, as contiguous slices.
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        // A zero width grid has no data, so any chunk size yields no rows
+        self.data.chunks_exact(self.width.max(1))
+    }
+
+    /// Items of row `y`, empty if it's outside the grid.
+    pub fn row(&self, y: usize) -> Iter<'_, T> {
+        self.rows().nth(y).unwrap_or_default().iter()
+    }
+
+    /// Items of column `x` from top to bottom, empty if it's outside the
+    /// grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.ray(Vector2d::new(x, 0), Direction::South)
+    }
+
+    /// Items from `start` towards `direction` until the edge of the grid,
+    /// starting with the one at `start`.
+    pub fn ray<D: AsVector + Copy>(
+        &self,
+        start: Vector2d<usize>,
+        direction: D,
+    ) -> impl Iterator<Item = &T> {
+        let mut position = self.has_position(start).then_some(start);
+
+        std::iter::from_fn(move || {
+            let current = position?;
+            position = self.step(current, direction, 1);
+            self.get_at(current)
+        })
+    }
+
+    /// Every diagonal going down and to the right, followed by every one
+    /// going down and to the left, each from its top end.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        // Every start position would be outside of an empty grid
+        let (width, height) = if self.width == 0 || self.height == 0 {
+            (0, 0)
         } else {
-            Some(&self.data[y * self.width + x])
+            (self.width, self.height)
+        };
+        let last_column = width.saturating_sub(1);
+
+        let down_right = (0..width)
+            .rev()
+            .map(|x| Vector2d::new(x, 0))
+            .chain((1..height).map(|y| Vector2d::new(0, y)))
+            .map(|start| (start, Direction8::SouthEast));
+        let down_left = (0..width)
+            .map(|x| Vector2d::new(x, 0))
+            .chain((1..height).map(move |y| Vector2d::new(last_column, y)))
+            .map(|start| (start, Direction8::SouthWest));
+
+        down_right
+            .chain(down_left)
+            .map(|(start, direction)| self.ray(start, direction))
+    }
+
+    /// View of the `width` by `height` area with its top left corner at
+    /// `position`, if it fits in the grid.
+    pub fn window(
+        &self,
+        position: Vector2d<usize>,
+        width: usize,
+        height: usize,
+    ) -> Option<Window<'_, T>> {
+        let fits = position.x.checked_add(width)? <= self.width
+            && position.y.checked_add(height)? <= self.height;
+
+        fits.then_some(Window {
+            grid: self,
+            origin: position,
+            width,
+            height,
+        })
+    }
+
+    /// Orthogonal neighbors of `position` that are inside the grid.
+    pub fn neighbors4(
+        &self,
+        position: Vector2d<usize>,
+    ) -> impl Iterator<Item = (Direction, Vector2d<usize>, &T)> {
+        self.neighbors(position, Direction::ALL)
+    }
+
+    /// Orthogonal and diagonal neighbors of `position` that are inside the
+    /// grid.
+    pub fn neighbors8(
+        &self,
+        position: Vector2d<usize>,
+    ) -> impl Iterator<Item = (Direction8, Vector2d<usize>, &T)> {
+        self.neighbors(position, Direction8::ALL)
+    }
+
+    fn neighbors<D: AsVector + Copy, const N: usize>(
+        &self,
+        position: Vector2d<usize>,
+        directions: [D; N],
+    ) -> impl Iterator<Item = (D, Vector2d<usize>, &T)> {
+        directions.into_iter().filter_map(move |direction| {
+            let neighbor = self.step(position, direction, 1)?;
+            let item = self.get_at(neighbor)?;
+            Some((direction, neighbor, item))
+        })
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    fn from_fn(width: usize, height: usize, mut item: impl FnMut(usize, usize) -> T) -> Self {
+        let data = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| item(x, y))
+            .collect();
+
+        Self {
+            width,
+            height,
+            data,
+        }
+    }
+
+    fn item(&self, x: usize, y: usize) -> T {
+        self.data[y * self.width + x].clone()
+    }
+
+    /// Mirrors the grid along its main diagonal, swapping rows and columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| self.item(y, x))
+    }
+
+    pub fn rotate_cw(&self) -> Self {
+        let height = self.height;
+        Self::from_fn(height, self.width, |x, y| self.item(y, height - 1 - x))
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        let width = self.width;
+        Self::from_fn(self.height, width, |x, y| self.item(width - 1 - y, x))
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let width = self.width;
+        Self::from_fn(width, self.height, |x, y| self.item(width - 1 - x, y))
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let height = self.height;
+        Self::from_fn(self.width, height, |x, y| self.item(x, height - 1 - y))
+    }
+
+    /// The four rotations of the grid followed by the four rotations of its
+    /// mirror image.
+    pub fn symmetries(&self) -> [Self; 8] {
+        let rotations = |grid: Self| {
+            let cw = grid.rotate_cw();
+            let half = cw.rotate_cw();
+            let ccw = grid.rotate_ccw();
+            [grid, cw, half, ccw]
+        };
+
+        let [a, b, c, d] = rotations(self.clone());
+        let [e, f, g, h] = rotations(self.flip_horizontal());
+        [a, b, c, d, e, f, g, h]
+    }
+}
+
+impl<T: Clone + PartialEq> Grid<T> {
+    /// Top left corners of every place where `pattern` matches in any of
+    /// its 8 symmetries, in row order. `None` cells in the pattern match
+    /// anything. An empty pattern matches nowhere.
+    pub fn find_pattern(&self, pattern: &Grid<Option<T>>) -> Vec<Vector2d<usize>> {
+        if pattern.width == 0 || pattern.height == 0 {
+            return Vec::new();
         }
+
+        let mut patterns: Vec<Grid<Option<T>>> = Vec::new();
+
+        for symmetry in pattern.symmetries() {
+            if !patterns.contains(&symmetry) {
+                patterns.push(symmetry);
+            }
+        }
+
+        self.item_indices()
+            .map(|(position, _)| position)
+            .filter(|&position| {
+                patterns.iter().any(|pattern| {
+                    self.window(position, pattern.width, pattern.height)
+                        .is_some_and(|window| window.matches(pattern))
+                })
+            })
+            .collect()
+    }
+}
+
+/// A rectangular area of a grid, see `Grid::window`. Positions are relative
+/// to the top left corner of the area.
+#[derive(Debug, Clone, Copy)]
+pub struct Window<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Vector2d<usize>,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> Window<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
     }
 
-    pub fn set(&mut self, x: usize, y: usize, value: T) -> Result<(), ()> {
-        if x > self.width - 1 || y > self.height - 1 {
-            Err(())
+    pub fn get_at(&self, position: Vector2d<usize>) -> Option<&'a T> {
+        if position.x >= self.width || position.y >= self.height {
+            None
         } else {
-            self.data[y * self.width + x] = value;
-            Ok(())
+            self.grid.get_at(self.origin + position)
         }
     }
 
-    pub fn has_position(&self, position: Vector2d<usize>) -> bool {
-        position.x < self.width && position.y < self.height
+    /// Rows of the area, top to bottom, as slices of the grid.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let (x, width) = (self.origin.x, self.width);
+
+        self.grid
+            .rows()
+            .skip(self.origin.y)
+            .take(self.height)
+            .map(move |row| &row[x..x + width])
+    }
+
+    /// Whether every cell matches the pattern, which must be the same size
+    /// as the window. `None` cells in the pattern match anything.
+    pub fn matches(&self, pattern: &Grid<Option<T>>) -> bool
+    where
+        T: PartialEq,
+    {
+        pattern.width == self.width
+            && pattern.height == self.height
+            && self.rows().zip(pattern.rows()).all(|(row, pattern_row)| {
+                row.iter()
+                    .zip(pattern_row)
+                    .all(|(item, expected)| expected.as_ref().is_none_or(|e| e == item))
+            })
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            data: self.rows().flatten().cloned().collect(),
+        }
+    }
+}
+
+/// ANSI terminal colours for overlays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    fn ansi_code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+}
+
+/// A set of positions to highlight when rendering a grid.
+#[derive(Debug, Clone)]
+pub struct Overlay {
+    positions: HashSet<Vector2d<usize>>,
+    marker: char,
+    color: Option<Color>,
+}
+
+impl Overlay {
+    /// Draws `marker` instead of the cell at each of `positions`.
+    pub fn new(positions: impl IntoIterator<Item = Vector2d<usize>>, marker: char) -> Self {
+        Self {
+            positions: positions.into_iter().collect(),
+            marker,
+            color: None,
+        }
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    fn write_marker(&self, out: &mut String) {
+        match self.color {
+            Some(color) => out.push_str(&format!(
+                "\x1b[{}m{}\x1b[0m",
+                color.ansi_code(),
+                self.marker
+            )),
+            None => out.push(self.marker),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Draws the grid one row per line, converting each cell with `cell`.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.render_with(cell, &[])
+    }
+
+    /// Like `render`, but positions in any of the overlays are replaced by
+    /// its marker. Later overlays are drawn over earlier ones.
+    pub fn render_with(&self, cell: impl Fn(&T) -> char, overlays: &[Overlay]) -> String {
+        let mut out = String::new();
+
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                out.push('\n');
+            }
+
+            for (x, item) in row.iter().enumerate() {
+                let position = Vector2d::new(x, y);
+
+                match overlays
+                    .iter()
+                    .rev()
+                    .find(|overlay| overlay.positions.contains(&position))
+                {
+                    Some(overlay) => overlay.write_marker(&mut out),
+                    None => out.push(cell(item)),
+                }
+            }
+        }
+
+        out
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|&ch| ch))
+    }
+}
+
+impl<T> GridLike for Grid<T> {
+    type Item = T;
+
+    fn get_signed(&self, position: Vector2d<isize>) -> Option<&T> {
+        Grid::get_signed(self, position)
+    }
+
+    fn in_bounds(&self, position: Vector2d<isize>) -> bool {
+        self.get_signed(position).is_some()
+    }
+
+    fn bounding_box(&self) -> Option<Bounds> {
+        Bounds::of_size(self.width, self.height)
+    }
+
+    fn items(&self) -> impl Iterator<Item = (Vector2d<isize>, &T)> {
+        self.item_indices().map(|(position, item)| {
+            let position = position
+                .try_components_into()
+                .expect("grid positions fit in isize");
+            (position, item)
+        })
+    }
+}
+
+impl<T> Index<Vector2d<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Vector2d<usize>) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get_at(position).unwrap_or_else(|| {
+            panic!(
+                "{}",
+                OutOfBounds {
+                    position,
+                    width,
+                    height
+                }
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Vector2d<usize>> for Grid<T> {
+    fn index_mut(&mut self, position: Vector2d<usize>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position).unwrap_or_else(|| {
+            panic!(
+                "{}",
+                OutOfBounds {
+                    position,
+                    width,
+                    height
+                }
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn char_grid(text: &str) -> Grid<char> {
+        Grid::from_text(text, |ch| ch).unwrap()
+    }
+
+    #[test]
+    fn from_text_rectangular() {
+        let grid = char_grid("abc\ndef\n");
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(0, 0), Some(&'a'));
+        assert_eq!(grid.get(2, 0), Some(&'c'));
+        assert_eq!(grid.get(0, 1), Some(&'d'));
+        assert_eq!(grid.get(2, 1), Some(&'f'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+    }
+
+    #[test]
+    fn from_text_single_row_and_column() {
+        let row = char_grid("abcd");
+        assert_eq!((row.width(), row.height()), (4, 1));
+        assert_eq!(row.get(3, 0), Some(&'d'));
+        assert_eq!(row.get(0, 1), None);
+
+        let column = char_grid("a\nb\nc\nd\n");
+        assert_eq!((column.width(), column.height()), (1, 4));
+        assert_eq!(column.get(0, 3), Some(&'d'));
+        assert_eq!(column.get(1, 0), None);
+    }
+
+    #[test]
+    fn from_text_empty() {
+        let grid = char_grid("");
+
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.get(0, 0), None);
+        assert!(!grid.has_position(Vector2d::new(0, 0)));
+        assert_eq!(grid.item_indices().count(), 0);
+    }
+
+    #[test]
+    fn from_text_rejects_empty_and_ragged_lines() {
+        assert!(matches!(
+            Grid::from_text("abc\n\nabc\n", |ch| ch),
+            Err(AocError::Parse {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Grid::from_text("abc\nabcd\n", |ch| ch),
+            Err(AocError::Parse {
+                line: 2,
+                column: 4,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Grid::from_text("abc\nab\n", |ch| ch),
+            Err(AocError::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn parser_handles_crlf_and_trailing_blank_lines() {
+        let grid = char_grid("abc\r\ndef\r\n\r\n\n");
+
+        assert_eq!(grid, char_grid("abc\ndef"));
+        assert_eq!(
+            GridParser::new().parse_bytes("ab\r\ncd\n\n").unwrap(),
+            Grid::from_text("ab\ncd", |ch| ch as u8).unwrap()
+        );
+    }
+
+    #[test]
+    fn parser_counts_characters_not_bytes() {
+        let grid = char_grid("aé\nbc\n");
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+
+        assert!(matches!(
+            GridParser::new().parse_bytes("ab\nbé\n"),
+            Err(AocError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn parser_trims_trailing_whitespace() {
+        let text = "ab  \ncd\t\n  \n";
+
+        assert!(Grid::from_text(text, |ch| ch).is_err());
+
+        let grid = GridParser::new()
+            .trim_trailing_whitespace(true)
+            .parse(text, Some)
+            .unwrap();
+        assert_eq!(grid, char_grid("ab\ncd\n"));
+    }
+
+    #[test]
+    fn converter_can_keep_state() {
+        let mut seen = 0;
+        let grid = Grid::from_text("ab\ncd\n", |_| {
+            seen += 1;
+            seen
+        })
+        .unwrap();
+
+        assert_eq!(grid.get(1, 1), Some(&4));
+    }
+
+    #[test]
+    fn try_from_text_reports_rejected_character() {
+        let grid = Grid::try_from_text("12\n3x\n", |ch| ch.to_digit(10));
+
+        assert!(matches!(
+            grid,
+            Err(AocError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn item_indices_non_square() {
+        let grid = char_grid("abc\ndef\n");
+        let indices: Vec<(Vector2d<usize>, char)> =
+            grid.item_indices().map(|(pos, ch)| (pos, *ch)).collect();
+
+        assert_eq!(
+            indices,
+            vec![
+                (Vector2d::new(0, 0), 'a'),
+                (Vector2d::new(1, 0), 'b'),
+                (Vector2d::new(2, 0), 'c'),
+                (Vector2d::new(0, 1), 'd'),
+                (Vector2d::new(1, 1), 'e'),
+                (Vector2d::new(2, 1), 'f'),
+            ]
+        );
+    }
+
+    #[test]
+    fn set_and_has_position() {
+        let mut grid: Grid<u8> = Grid::new(3, 2);
+
+        assert_eq!(grid.set(2, 1, 7), Ok(()));
+        assert_eq!(grid.get(2, 1), Some(&7));
+        assert_eq!(
+            grid.set(3, 1, 7),
+            Err(OutOfBounds {
+                position: Vector2d::new(3, 1),
+                width: 3,
+                height: 2
+            })
+        );
+        assert!(grid.set(0, 2, 7).is_err());
+
+        assert!(grid.has_position(Vector2d::new(2, 1)));
+        assert!(!grid.has_position(Vector2d::new(3, 1)));
+        assert!(!grid.has_position(Vector2d::new(2, 2)));
+    }
+
+    #[test]
+    fn new_with_zero_size() {
+        let mut grid: Grid<u8> = Grid::new(0, 3);
+
+        assert_eq!(grid.get(0, 0), None);
+        assert!(grid.set(0, 0, 1).is_err());
+    }
+
+    #[test]
+    fn index_by_position() {
+        let mut grid = char_grid("abc\ndef\n");
+        let position = Vector2d::new(1, 1);
+
+        assert_eq!(grid[position], 'e');
+        assert_eq!(grid.get_at(position), Some(&'e'));
+        assert_eq!(grid.get_at(Vector2d::new(3, 0)), None);
+
+        grid[position] = 'x';
+        *grid.get_mut(Vector2d::new(0, 0)).unwrap() = 'y';
+        assert_eq!(grid.get(1, 1), Some(&'x'));
+        assert_eq!(grid.get(0, 0), Some(&'y'));
+        assert_eq!(grid.get_mut(Vector2d::new(0, 2)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the 3x2 grid")]
+    fn index_out_of_bounds_panics() {
+        let grid = char_grid("abc\ndef\n");
+        let _ = grid[Vector2d::new(0, 2)];
+    }
+
+    #[test]
+    fn get_signed_rejects_negative_coordinates() {
+        let grid = char_grid("abc\ndef\n");
+
+        assert_eq!(grid.get_signed(Vector2d::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get_signed(Vector2d::new(-1, 0)), None);
+        assert_eq!(grid.get_signed(Vector2d::new(0, -1)), None);
+        assert_eq!(grid.get_signed(Vector2d::new(3, 1)), None);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = char_grid("abc\ndef\n");
+
+        let rows: Vec<&[char]> = grid.rows().collect();
+        assert_eq!(rows, vec![&['a', 'b', 'c'][..], &['d', 'e', 'f'][..]]);
+
+        assert_eq!(grid.row(1).collect::<String>(), "def");
+        assert_eq!(grid.row(2).count(), 0);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.column(3).count(), 0);
+
+        let empty: Grid<char> = Grid::new(0, 3);
+        assert_eq!(empty.rows().count(), 0);
+        assert_eq!(empty.column(0).count(), 0);
+    }
+
+    #[test]
+    fn ray_stops_at_the_edge() {
+        let grid = char_grid("abc\ndef\nghi\n");
+
+        let ray =
+            |x, y, direction| -> String { grid.ray(Vector2d::new(x, y), direction).collect() };
+
+        assert_eq!(ray(0, 0, Direction8::SouthEast), "aei");
+        assert_eq!(ray(2, 0, Direction8::SouthWest), "ceg");
+        assert_eq!(ray(1, 2, Direction8::North), "heb");
+        assert_eq!(ray(1, 1, Direction8::West), "ed");
+        assert_eq!(ray(3, 0, Direction8::West), "");
+    }
+
+    #[test]
+    fn diagonals_of_non_square_grid() {
+        let grid = char_grid("abc\ndef\n");
+
+        let diagonals: Vec<String> = grid
+            .diagonals()
+            .map(|diagonal| diagonal.collect())
+            .collect();
+
+        assert_eq!(diagonals, vec!["c", "bf", "ae", "d", "a", "bd", "ce", "f"]);
+
+        assert_eq!(Grid::<char>::new(0, 3).diagonals().count(), 0);
+        assert_eq!(Grid::<char>::new(3, 0).diagonals().count(), 0);
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = char_grid("abc\ndef\n");
+
+        assert_eq!(grid.transpose(), char_grid("ad\nbe\ncf\n"));
+        assert_eq!(grid.rotate_cw(), char_grid("da\neb\nfc\n"));
+        assert_eq!(grid.rotate_ccw(), char_grid("cf\nbe\nad\n"));
+        assert_eq!(grid.flip_horizontal(), char_grid("cba\nfed\n"));
+        assert_eq!(grid.flip_vertical(), char_grid("def\nabc\n"));
+
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+        assert_eq!(
+            grid.rotate_cw().rotate_cw(),
+            grid.flip_horizontal().flip_vertical()
+        );
+    }
+
+    #[test]
+    fn symmetries_are_distinct_for_asymmetric_grid() {
+        let grid = char_grid("abc\ndef\n");
+        let symmetries = grid.symmetries();
+
+        for (idx, symmetry) in symmetries.iter().enumerate() {
+            assert!(!symmetries[idx + 1..].contains(symmetry));
+        }
+        assert!(symmetries.contains(&grid.transpose()));
+    }
+
+    #[test]
+    fn window_views() {
+        let grid = char_grid("abcd\nefgh\nijkl\n");
+
+        let window = grid.window(Vector2d::new(1, 1), 2, 2).unwrap();
+        assert_eq!((window.width(), window.height()), (2, 2));
+        assert_eq!(window.get_at(Vector2d::new(1, 0)), Some(&'g'));
+        assert_eq!(window.get_at(Vector2d::new(2, 0)), None);
+        assert_eq!(window.to_grid(), char_grid("fg\njk\n"));
+
+        assert!(grid.window(Vector2d::new(3, 0), 1, 3).is_some());
+        assert!(grid.window(Vector2d::new(3, 0), 2, 1).is_none());
+        assert!(grid.window(Vector2d::new(0, 1), 1, 3).is_none());
+    }
+
+    #[test]
+    fn find_pattern_in_any_orientation() {
+        let grid = char_grid("ab..\n....\n..ba\n.a..\n.b..\n");
+        let pattern = Grid::from_text("ab", Some).unwrap();
+
+        assert_eq!(
+            grid.find_pattern(&pattern),
+            vec![
+                Vector2d::new(0, 0),
+                Vector2d::new(2, 2),
+                Vector2d::new(1, 3)
+            ]
+        );
+
+        let wildcard = Grid::from_text("a.\n.a", |ch| (ch != '.').then_some(ch)).unwrap();
+        let grid = char_grid("ax\nya\n");
+        assert_eq!(grid.find_pattern(&wildcard), vec![Vector2d::new(0, 0)]);
+
+        assert_eq!(grid.find_pattern(&Grid::new(0, 0)), vec![]);
+        assert_eq!(grid.find_pattern(&Grid::new(0, 2)), vec![]);
+    }
+
+    #[test]
+    fn display_keeps_layout() {
+        let grid = char_grid("abc\ndef\n");
+
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert_eq!(char_grid(&grid.to_string()), grid);
+        assert_eq!(char_grid("").to_string(), "");
+    }
+
+    #[test]
+    fn render_with_overlays() {
+        let grid = Grid::try_from_text("012\n345\n", |ch| ch.to_digit(10)).unwrap();
+
+        assert_eq!(
+            grid.render(|&digit| char::from_digit(digit, 10).unwrap()),
+            "012\n345"
+        );
+
+        let visited = Overlay::new([Vector2d::new(0, 0), Vector2d::new(1, 1)], 'X');
+        let current = Overlay::new([Vector2d::new(1, 1)], '@').with_color(Color::Red);
+
+        assert_eq!(
+            grid.render_with(|_| '.', &[visited, current]),
+            "X..\n.\x1b[31m@\x1b[0m."
+        );
+    }
+
+    #[test]
+    fn find_and_index_by_value() {
+        let grid = char_grid("a.b\n.a.\n");
+
+        assert_eq!(grid.find(&'a'), Some(Vector2d::new(0, 0)));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(
+            grid.find_all(|&ch| ch == '.'),
+            vec![
+                Vector2d::new(1, 0),
+                Vector2d::new(0, 1),
+                Vector2d::new(2, 1)
+            ]
+        );
+
+        let index = grid.index_by(|&ch| (ch != '.').then_some(ch));
+        assert_eq!(index.len(), 2);
+        assert_eq!(index[&'a'], vec![Vector2d::new(0, 0), Vector2d::new(1, 1)]);
+        assert_eq!(index[&'b'], vec![Vector2d::new(2, 0)]);
+    }
+
+    #[test]
+    fn step_stays_in_bounds() {
+        let grid = char_grid("abc\ndef\n");
+        let origin = Vector2d::new(0, 0);
+
+        assert_eq!(
+            grid.step(origin, Direction::East, 2),
+            Some(Vector2d::new(2, 0))
+        );
+        assert_eq!(grid.step(origin, Direction::East, 3), None);
+        assert_eq!(grid.step(origin, Direction::North, 1), None);
+        assert_eq!(
+            grid.step(origin, Direction8::SouthEast, 1),
+            Some(Vector2d::new(1, 1))
+        );
+        assert_eq!(grid.step(origin, Direction::South, 0), Some(origin));
+        assert_eq!(grid.step(origin, Direction::South, usize::MAX), None);
+    }
+
+    #[test]
+    fn neighbors_skip_out_of_bounds() {
+        let grid = char_grid("abc\ndef\n");
+
+        let corner: Vec<(Direction, char)> = grid
+            .neighbors4(Vector2d::new(0, 0))
+            .map(|(dir, _, ch)| (dir, *ch))
+            .collect();
+        assert_eq!(
+            corner,
+            vec![(Direction::East, 'b'), (Direction::South, 'd')]
+        );
+
+        let middle: Vec<(Direction8, Vector2d<usize>, char)> = grid
+            .neighbors8(Vector2d::new(1, 1))
+            .map(|(dir, pos, ch)| (dir, pos, *ch))
+            .collect();
+        assert_eq!(
+            middle,
+            vec![
+                (Direction8::North, Vector2d::new(1, 0), 'b'),
+                (Direction8::NorthEast, Vector2d::new(2, 0), 'c'),
+                (Direction8::East, Vector2d::new(2, 1), 'f'),
+                (Direction8::West, Vector2d::new(0, 1), 'd'),
+                (Direction8::NorthWest, Vector2d::new(0, 0), 'a'),
+            ]
+        );
+    }
+
+    /// Random rectangular grids as rows of characters.
+    fn rows() -> impl Strategy<Value = Vec<Vec<char>>> {
+        (1usize..8, 1usize..8).prop_flat_map(|(width, height)| {
+            prop::collection::vec(
+                prop::collection::vec(prop::sample::select(vec!['.', '#', 'a', '0']), width),
+                height,
+            )
+        })
+    }
+
+    fn to_text(rows: &[Vec<char>]) -> String {
+        rows.iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn matches_naive_reference(rows in rows()) {
+            let grid = char_grid(&to_text(&rows));
+            let (width, height) = (rows[0].len(), rows.len());
+
+            prop_assert_eq!((grid.width(), grid.height()), (width, height));
+
+            for y in 0..height + 2 {
+                for x in 0..width + 2 {
+                    let expected = rows.get(y).and_then(|row| row.get(x));
+                    prop_assert_eq!(grid.get(x, y), expected);
+                    prop_assert_eq!(grid.has_position(Vector2d::new(x, y)), expected.is_some());
+                }
+            }
+
+            let expected: Vec<(Vector2d<usize>, char)> = rows
+                .iter()
+                .enumerate()
+                .flat_map(|(y, row)| {
+                    row.iter().enumerate().map(move |(x, ch)| (Vector2d::new(x, y), *ch))
+                })
+                .collect();
+            let actual: Vec<(Vector2d<usize>, char)> =
+                grid.item_indices().map(|(pos, ch)| (pos, *ch)).collect();
+
+            prop_assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn set_matches_naive_reference(
+            mut rows in rows(),
+            writes in prop::collection::vec((0usize..10, 0usize..10), 0..20),
+        ) {
+            let mut grid = char_grid(&to_text(&rows));
+
+            for (x, y) in writes {
+                let in_bounds = y < rows.len() && x < rows[0].len();
+
+                prop_assert_eq!(grid.set(x, y, '@').is_ok(), in_bounds);
+
+                if in_bounds {
+                    rows[y][x] = '@';
+                }
+            }
+
+            prop_assert_eq!(grid.iter().collect::<String>(), rows.concat().iter().collect::<String>());
+        }
     }
 }
@@ -1,36 +1,122 @@
 use std::env;
 use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process::ExitCode;
 
+use error::AocError;
+use grid::GridParser;
+use output::print_answers;
+use solution::{solve, Solution};
 use vector::Vector2d;
 
+pub mod days;
 pub mod direction;
+pub mod error;
+pub mod geometry;
 pub mod grid;
+pub mod output;
+pub mod region;
+pub mod search;
+pub mod solution;
+pub mod sparse_grid;
 pub mod vector;
+pub mod verify;
 
-pub fn load_input() -> String {
-    let input_filename = env::args().nth(1).expect("Missing input file name");
+/// Directory holding the `dayNN.txt` inputs when `AOC_INPUT_DIR` isn't set.
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
 
-    let input_str = fs::read_to_string(input_filename).expect("Could not load input file");
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Interprets a command line argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    /// The conventional input file for `day`, `dayNN.txt` inside
+    /// `AOC_INPUT_DIR` or `inputs/`.
+    pub fn for_day(day: u8) -> Self {
+        let dir = env::var_os("AOC_INPUT_DIR").unwrap_or(DEFAULT_INPUT_DIR.into());
+        InputSource::File(PathBuf::from(dir).join(format!("day{day:02}.txt")))
+    }
 
-    input_str
+    pub fn read(&self) -> Result<String, AocError> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| AocError::Io {
+                        path: "<stdin>".to_string(),
+                        source,
+                    })?;
+                Ok(input)
+            }
+            InputSource::File(path) => fs::read_to_string(path).map_err(|source| AocError::Io {
+                path: path.display().to_string(),
+                source,
+            }),
+        }
+    }
 }
 
-pub fn grid_size(grid_str: &str) -> (usize, usize) {
-    let width = grid_str.lines().next().expect("Non empty grid").len();
-    let height = grid_str.lines().count();
+/// Loads the input for `day` from the path given as the first command line
+/// argument that isn't a `--flag`, or from its conventional location if
+/// there is none.
+pub fn load_input_for(day: u8) -> Result<String, AocError> {
+    let path = env::args().skip(1).find(|arg| !arg.starts_with("--"));
+
+    let source = match path {
+        Some(arg) => InputSource::from_arg(&arg),
+        None => InputSource::for_day(day),
+    };
+
+    source.read()
+}
+
+/// Entry point shared by the single day binaries. Passing `--time` also
+/// prints how long each step took.
+pub fn run<S: Solution>() -> ExitCode {
+    let show_time = env::args().any(|arg| arg == "--time");
+
+    match load_input_for(S::DAY).and_then(|input| solve::<S>(&input)) {
+        Ok(answers) => {
+            print_answers(&answers, show_time);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Width and height of a rectangular text grid.
+pub fn grid_size(grid_str: &str) -> Result<(usize, usize), AocError> {
+    let grid = GridParser::new().parse(grid_str, |_| Some(()))?;
+
+    if grid.height() == 0 {
+        return Err(AocError::InvalidInput("Empty grid".to_string()));
+    }
 
-    (width, height)
+    Ok((grid.width(), grid.height()))
 }
 
-pub fn grid_indices<'grid>(
-    grid_str: &'grid str,
-) -> Box<dyn Iterator<Item = (Vector2d<usize>, char)> + 'grid> {
-    let width = grid_str.lines().next().expect("Non empty grid").len();
-    Box::new(
-        grid_str
-            .chars()
-            .filter(|ch| *ch != '\n')
+/// Characters in `grid_str` along with their position, ignoring line
+/// endings.
+pub fn grid_indices(grid_str: &str) -> impl Iterator<Item = (Vector2d<usize>, char)> + '_ {
+    grid_str.lines().enumerate().flat_map(|(y, line)| {
+        line.chars()
             .enumerate()
-            .map(move |(idx, ch)| (Vector2d::new(idx % width, idx / width), ch)),
-    )
+            .map(move |(x, ch)| (Vector2d::new(x, y), ch))
+    })
 }
@@ -0,0 +1,166 @@
+use std::{
+    fmt::{self, Display, Write},
+    time::Duration,
+};
+
+use crate::solution::{Answer, Answers, Timings};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    /// A JSON object per day, or an array of them for several days.
+    Json,
+    /// A JSON object per day, one per line.
+    JsonLines,
+}
+
+impl OutputFormat {
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "text" => OutputFormat::Text,
+            "json" => OutputFormat::Json,
+            "jsonl" => OutputFormat::JsonLines,
+            _ => return None,
+        })
+    }
+}
+
+/// Displays a duration with a unit suited to its magnitude, e.g. `12.3 ms`.
+pub struct HumanDuration(pub Duration);
+
+impl Display for HumanDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.0.as_nanos() as f64;
+
+        let text = if nanos < 1e3 {
+            format!("{nanos} ns")
+        } else if nanos < 1e6 {
+            format!("{:.1} µs", nanos / 1e3)
+        } else if nanos < 1e9 {
+            format!("{:.1} ms", nanos / 1e6)
+        } else {
+            format!("{:.2} s", nanos / 1e9)
+        };
+
+        // Go through pad so width and alignment flags work
+        f.pad(&text)
+    }
+}
+
+/// Prints the answers of a day, along with how long each step took if
+/// `show_time` is set.
+pub fn print_answers(answers: &Answers, show_time: bool) {
+    let timings = &answers.timings;
+
+    if show_time {
+        println!("Parse:  {}", HumanDuration(timings.parse));
+    }
+
+    for (name, answer, time) in [
+        ("Part 1", &answers.part1, timings.part1),
+        ("Part 2", &answers.part2, timings.part2),
+    ] {
+        let time = if show_time {
+            format!(" ({})", HumanDuration(time))
+        } else {
+            String::new()
+        };
+
+        match answer {
+            // Multi-line answers start on their own line to keep them aligned
+            Answer::Text(text) if text.contains('\n') => println!("{name}:{time}\n{text}"),
+            answer => println!("{name}: {answer}{time}"),
+        }
+    }
+}
+
+/// Prints a table with the timings of several days and their total.
+pub fn print_timing_summary(timings: &[(u8, Timings)]) {
+    println!(
+        "{:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+
+    for (day, timing) in timings {
+        println!(
+            "{:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+            day,
+            HumanDuration(timing.parse),
+            HumanDuration(timing.part1),
+            HumanDuration(timing.part2),
+            HumanDuration(timing.total()),
+        );
+    }
+
+    let total: Duration = timings.iter().map(|(_, timing)| timing.total()).sum();
+    println!("{:>3}  {:>46}", "All", HumanDuration(total));
+}
+
+/// Writes `text` as a JSON string literal.
+fn write_json_string(out: &mut String, text: &str) {
+    out.push('"');
+
+    for ch in text.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if ch.is_control() => write!(out, "\\u{:04x}", ch as u32).unwrap(),
+            ch => out.push(ch),
+        }
+    }
+
+    out.push('"');
+}
+
+fn write_json_answer(out: &mut String, answer: &Answer) {
+    match answer {
+        Answer::Number(number) => write!(out, "{number}").unwrap(),
+        Answer::Text(text) => write_json_string(out, text),
+    }
+}
+
+/// Formats the answers of a day as a single line JSON object, e.g.
+/// `{"day":7,"part1":3749,"part2":11387,"timings_ns":{...}}`.
+pub fn answers_json(day: u8, answers: &Answers) -> String {
+    let mut out = String::new();
+
+    write!(out, "{{\"day\":{day},\"part1\":").unwrap();
+    write_json_answer(&mut out, &answers.part1);
+    out.push_str(",\"part2\":");
+    write_json_answer(&mut out, &answers.part2);
+
+    let timings = &answers.timings;
+    write!(
+        out,
+        ",\"timings_ns\":{{\"parse\":{},\"part1\":{},\"part2\":{},\"total\":{}}}}}",
+        timings.parse.as_nanos(),
+        timings.part1.as_nanos(),
+        timings.part2.as_nanos(),
+        timings.total().as_nanos(),
+    )
+    .unwrap();
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_keeps_numbers_and_escapes_text() {
+        let answers = Answers {
+            part1: Answer::from(65601038650482u64),
+            part2: Answer::from("#..#\n\"ok\"\\"),
+            timings: Timings::default(),
+        };
+
+        assert_eq!(
+            answers_json(11, &answers),
+            r##"{"day":11,"part1":65601038650482,"part2":"#..#\n\"ok\"\\","timings_ns":{"parse":0,"part1":0,"part2":0,"total":0}}"##
+        );
+    }
+}
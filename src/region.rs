@@ -0,0 +1,224 @@
+use std::collections::HashSet;
+
+use crate::direction::Direction;
+use crate::grid::Grid;
+use crate::search::bfs;
+use crate::vector::Vector2d;
+
+/// Orthogonally connected positions of a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    positions: HashSet<Vector2d<usize>>,
+}
+
+impl Region {
+    pub fn contains(&self, position: Vector2d<usize>) -> bool {
+        self.positions.contains(&position)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Vector2d<usize>> + '_ {
+        self.positions.iter().copied()
+    }
+
+    pub fn area(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Whether there's a fence on the `direction` side of `position`.
+    fn has_fence(&self, position: Vector2d<usize>, direction: Direction) -> bool {
+        position
+            .checked_offset(direction, 1)
+            .is_none_or(|next| !self.contains(next))
+    }
+
+    /// Number of unit fence segments around the region, including the ones
+    /// around holes.
+    pub fn perimeter(&self) -> usize {
+        self.positions()
+            .flat_map(|position| Direction::ALL.map(|direction| (position, direction)))
+            .filter(|&(position, direction)| self.has_fence(position, direction))
+            .count()
+    }
+
+    /// Number of straight fence runs around the region. Each run is counted
+    /// at its end where turning right from the fence leaves the run.
+    pub fn sides(&self) -> usize {
+        self.positions()
+            .flat_map(|position| Direction::ALL.map(|direction| (position, direction)))
+            .filter(|&(position, direction)| {
+                if !self.has_fence(position, direction) {
+                    return false;
+                }
+
+                match position.checked_offset(direction.rotate(1), 1) {
+                    Some(along) if self.contains(along) => !self.has_fence(along, direction),
+                    _ => true,
+                }
+            })
+            .count()
+    }
+}
+
+/// Every region of a grid, along with the index of the region each
+/// position belongs to.
+#[derive(Debug, Clone)]
+pub struct Components {
+    pub labels: Grid<usize>,
+    pub regions: Vec<Region>,
+}
+
+impl Components {
+    pub fn region_at(&self, position: Vector2d<usize>) -> Option<&Region> {
+        self.labels
+            .get_at(position)
+            .and_then(|&label| self.regions.get(label))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Region of positions reachable from `start` through items matching
+    /// `predicate`. Empty if `start` itself doesn't match.
+    pub fn flood_fill(&self, start: Vector2d<usize>, predicate: impl Fn(&T) -> bool) -> Region {
+        self.fill(start, |_, item| predicate(item))
+    }
+
+    /// Like `flood_fill`, but the predicate also gets the position of the
+    /// item.
+    fn fill(&self, start: Vector2d<usize>, accept: impl Fn(Vector2d<usize>, &T) -> bool) -> Region {
+        if !self.get_at(start).is_some_and(|item| accept(start, item)) {
+            return Region {
+                positions: HashSet::new(),
+            };
+        }
+
+        let reached = bfs(start, |&position| {
+            self.neighbors4(position)
+                .filter(|&(_, next, item)| accept(next, item))
+                .map(|(_, next, _)| next)
+                .collect::<Vec<_>>()
+        });
+
+        Region {
+            positions: reached.distances.into_keys().collect(),
+        }
+    }
+
+    /// Splits the grid into regions of neighbors for which `eq` holds with
+    /// the first item of the region found in row order.
+    pub fn components(&self, eq: impl Fn(&T, &T) -> bool) -> Components {
+        let mut labels: Grid<Option<usize>> = Grid::new(self.width(), self.height());
+        let mut regions: Vec<Region> = Vec::new();
+
+        for (start, item) in self.item_indices() {
+            if labels[start].is_some() {
+                continue;
+            }
+
+            // Skipping labelled positions keeps the regions apart when `eq`
+            // isn't transitive
+            let region = self.fill(start, |position, other| {
+                labels[position].is_none() && eq(item, other)
+            });
+
+            for position in region.positions() {
+                labels[position] = Some(regions.len());
+            }
+            regions.push(region);
+        }
+
+        Components {
+            labels: labels.map(|label| label.expect("every position is in a region")),
+            regions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fence_prices(garden: &str) -> (usize, usize) {
+        let grid = Grid::from_text(garden, |ch| ch).unwrap();
+        let components = grid.components(|a, b| a == b);
+
+        components
+            .regions
+            .iter()
+            .map(|region| {
+                (
+                    region.area() * region.perimeter(),
+                    region.area() * region.sides(),
+                )
+            })
+            .fold((0, 0), |(perimeter, sides), (a, b)| {
+                (perimeter + a, sides + b)
+            })
+    }
+
+    #[test]
+    fn region_measurements() {
+        let grid = Grid::from_text("AAAA\nBBCD\nBBCC\nEEEC\n", |ch| ch).unwrap();
+        let components = grid.components(|a, b| a == b);
+
+        let measure = |x, y| {
+            let region = components.region_at(Vector2d::new(x, y)).unwrap();
+            (region.area(), region.perimeter(), region.sides())
+        };
+
+        assert_eq!(components.regions.len(), 5);
+        assert_eq!(measure(0, 0), (4, 10, 4));
+        assert_eq!(measure(0, 1), (4, 8, 4));
+        assert_eq!(measure(2, 1), (4, 10, 8));
+        assert_eq!(measure(3, 1), (1, 4, 4));
+        assert_eq!(measure(0, 3), (3, 8, 4));
+        assert_eq!(
+            components.labels[Vector2d::new(3, 3)],
+            components.labels[Vector2d::new(2, 2)]
+        );
+    }
+
+    #[test]
+    fn regions_with_holes() {
+        assert_eq!(
+            fence_prices("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n"),
+            (772, 436)
+        );
+        assert_eq!(fence_prices("EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE\n").1, 236);
+        assert_eq!(
+            fence_prices("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA\n").1,
+            368
+        );
+    }
+
+    #[test]
+    fn flood_fill_follows_predicate() {
+        let grid = Grid::from_text("1.2\n..3\n4..\n", |ch| ch).unwrap();
+
+        let region = grid.flood_fill(Vector2d::new(1, 1), |&ch| ch == '.');
+        assert_eq!(region.area(), 5);
+        assert!(region.contains(Vector2d::new(0, 1)));
+        assert!(!region.contains(Vector2d::new(0, 0)));
+
+        assert_eq!(
+            grid.flood_fill(Vector2d::new(0, 0), |&ch| ch == '.').area(),
+            0
+        );
+        assert_eq!(grid.flood_fill(Vector2d::new(5, 5), |_| true).area(), 0);
+    }
+
+    #[test]
+    fn components_partition_with_intransitive_eq() {
+        let grid = Grid::try_from_text("123\n", |ch| ch.to_digit(10)).unwrap();
+        let components = grid.components(|a, b| a.abs_diff(*b) <= 1);
+
+        assert_eq!(components.regions.len(), 2);
+        assert_eq!(
+            components.regions.iter().map(Region::area).sum::<usize>(),
+            3
+        );
+
+        for (position, &label) in components.labels.item_indices() {
+            assert!(components.regions[label].contains(position));
+        }
+    }
+}
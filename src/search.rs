@@ -0,0 +1,455 @@
+//! Graph searches over implicit graphs. Nodes are any hashable value, and
+//! edges are given by a successor closure, so the same functions work for
+//! grid positions, `(position, direction)` states and anything else.
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use num_traits::Zero;
+
+/// Shortest path information from a single start node to every reachable
+/// node.
+#[derive(Debug, Clone)]
+pub struct SearchResult<N, C> {
+    /// Cost of the shortest path to each reachable node.
+    pub distances: HashMap<N, C>,
+    /// Every node that comes right before each node in some shortest path.
+    pub predecessors: HashMap<N, Vec<N>>,
+    /// Number of distinct shortest paths to each reachable node.
+    pub path_counts: HashMap<N, usize>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> SearchResult<N, C> {
+    fn new(start: N) -> Self
+    where
+        C: Zero,
+    {
+        Self {
+            distances: HashMap::from([(start.clone(), C::zero())]),
+            predecessors: HashMap::new(),
+            path_counts: HashMap::from([(start, 1)]),
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn path_count(&self, node: &N) -> usize {
+        self.path_counts.get(node).copied().unwrap_or(0)
+    }
+
+    /// One of the shortest paths to `node`, starting at the start node.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        let mut seen: HashSet<&N> = HashSet::from([node]);
+
+        // Zero cost edges can lead back to a node already on the path
+        while let Some(previous) = self
+            .predecessors
+            .get(path.last().unwrap())
+            .and_then(|previous| previous.iter().find(|&previous| seen.insert(previous)))
+        {
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// Records that `node` can be reached with `cost` coming from `from`.
+    /// Returns whether this is a new best cost.
+    fn relax(&mut self, from: &N, node: N, cost: C) -> bool
+    where
+        C: PartialOrd,
+    {
+        let from_count = self.path_count(from);
+
+        match self.distances.get(&node) {
+            Some(best) if cost > *best => false,
+            // The start node is the only one reached without a predecessor,
+            // and a zero cost cycle back to it is not another path
+            Some(_) if !self.predecessors.contains_key(&node) => false,
+            Some(best) if cost == *best => {
+                *self.path_counts.entry(node.clone()).or_default() += from_count;
+                self.predecessors
+                    .entry(node)
+                    .or_default()
+                    .push(from.clone());
+                false
+            }
+            _ => {
+                self.distances.insert(node.clone(), cost);
+                self.path_counts.insert(node.clone(), from_count);
+                self.predecessors.insert(node, vec![from.clone()]);
+                true
+            }
+        }
+    }
+}
+
+/// Breadth first search, where every edge costs 1.
+pub fn bfs<N, F, I>(start: N, mut successors: F) -> SearchResult<N, usize>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new(start.clone());
+    let mut to_check: VecDeque<N> = VecDeque::from([start]);
+
+    while let Some(node) = to_check.pop_front() {
+        let cost = result.distances[&node] + 1;
+
+        for next in successors(&node) {
+            if result.relax(&node, next.clone(), cost) {
+                to_check.push_back(next);
+            }
+        }
+    }
+
+    result
+}
+
+/// Nodes reachable from a start node in depth first order, along with the
+/// node each one was discovered from.
+#[derive(Debug, Clone)]
+pub struct Traversal<N> {
+    pub order: Vec<N>,
+    pub parents: HashMap<N, N>,
+}
+
+impl<N: Eq + Hash + Clone> Traversal<N> {
+    /// Path from the start node to `node` following the discovery order.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.order.contains(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Depth first search, visiting successors in the order they are given.
+pub fn dfs<N, F, I>(start: N, mut successors: F) -> Traversal<N>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut order: Vec<N> = Vec::new();
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut visited: HashSet<N> = HashSet::new();
+    let mut to_check: Vec<(N, Option<N>)> = vec![(start, None)];
+
+    while let Some((node, parent)) = to_check.pop() {
+        if !visited.insert(node.clone()) {
+            continue;
+        }
+
+        if let Some(parent) = parent {
+            parents.insert(node.clone(), parent);
+        }
+
+        // Pushed in reverse so the first successor is visited first
+        let next: Vec<N> = successors(&node).into_iter().collect();
+        for next in next.into_iter().rev() {
+            if !visited.contains(&next) {
+                to_check.push((next, Some(node.clone())));
+            }
+        }
+
+        order.push(node);
+    }
+
+    Traversal { order, parents }
+}
+
+/// Heap entry ordered only by its cost, so nodes don't need to be `Ord`.
+struct Entry<N, C> {
+    cost: C,
+    node: N,
+}
+
+impl<N, C: PartialEq> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl<N, C: PartialEq> Eq for Entry<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cost.cmp(&other.cost)
+    }
+}
+
+/// Dijkstra's shortest paths. Successors come with the cost of reaching
+/// them, which must not be negative. Path counts also need every cost to be
+/// positive.
+pub fn dijkstra<N, C, F, I>(start: N, mut successors: F) -> SearchResult<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut result = SearchResult::new(start.clone());
+    let mut to_check = BinaryHeap::from([Reverse(Entry {
+        cost: C::zero(),
+        node: start,
+    })]);
+
+    while let Some(Reverse(Entry { cost, node })) = to_check.pop() {
+        // Stale entry, a cheaper path was found after pushing it
+        if cost > result.distances[&node] {
+            continue;
+        }
+
+        for (next, step_cost) in successors(&node) {
+            let next_cost = cost + step_cost;
+
+            if result.relax(&node, next.clone(), next_cost) {
+                to_check.push(Reverse(Entry {
+                    cost: next_cost,
+                    node: next,
+                }));
+            }
+        }
+    }
+
+    result
+}
+
+/// A* search for the cheapest path to a node accepted by `is_goal`. The
+/// heuristic must never overestimate the remaining cost. Returns the path,
+/// including the start and goal nodes, and its cost.
+pub fn astar<N, C, F, I, H, G>(
+    start: N,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut costs: HashMap<N, C> = HashMap::from([(start.clone(), C::zero())]);
+    let mut came_from: HashMap<N, N> = HashMap::new();
+    let mut to_check = BinaryHeap::from([Reverse(Entry {
+        cost: heuristic(&start),
+        node: start,
+    })]);
+
+    while let Some(Reverse(Entry { node, .. })) = to_check.pop() {
+        let cost = costs[&node];
+
+        if is_goal(&node) {
+            let mut path = vec![node];
+
+            while let Some(previous) = came_from.get(path.last().unwrap()) {
+                path.push(previous.clone());
+            }
+
+            path.reverse();
+            return Some((path, cost));
+        }
+
+        for (next, step_cost) in successors(&node) {
+            let next_cost = cost + step_cost;
+
+            if costs.get(&next).is_none_or(|best| next_cost < *best) {
+                costs.insert(next.clone(), next_cost);
+                came_from.insert(next.clone(), node.clone());
+                to_check.push(Reverse(Entry {
+                    cost: next_cost + heuristic(&next),
+                    node: next,
+                }));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{direction::Direction, grid::Grid, vector::Vector2d};
+
+    const MAZE: &str = "\
+S..#
+.#..
+...E
+";
+
+    fn open_neighbors(grid: &Grid<char>, position: Vector2d<usize>) -> Vec<Vector2d<usize>> {
+        grid.neighbors4(position)
+            .filter(|(_, _, ch)| **ch != '#')
+            .map(|(_, neighbor, _)| neighbor)
+            .collect()
+    }
+
+    #[test]
+    fn bfs_distances_paths_and_counts() {
+        let grid = Grid::from_text(MAZE, |ch| ch).unwrap();
+        let start = Vector2d::new(0, 0);
+        let end = Vector2d::new(3, 2);
+
+        let result = bfs(start, |&pos| open_neighbors(&grid, pos));
+
+        assert_eq!(result.distance(&end), Some(5));
+        assert_eq!(result.distance(&Vector2d::new(3, 0)), None);
+        // Any mix of right and down steps avoiding both walls
+        assert_eq!(result.path_count(&end), 3);
+
+        let path = result.path_to(&end).unwrap();
+        assert_eq!(path.len(), 6);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&end));
+    }
+
+    #[test]
+    fn dfs_visits_every_reachable_node_once() {
+        let grid = Grid::from_text(MAZE, |ch| ch).unwrap();
+        let start = Vector2d::new(0, 0);
+
+        let traversal = dfs(start, |&pos| open_neighbors(&grid, pos));
+
+        assert_eq!(traversal.order.len(), 10);
+        assert_eq!(traversal.order[0], start);
+
+        let path = traversal.path_to(&Vector2d::new(3, 2)).unwrap();
+        assert_eq!(path.first(), Some(&start));
+        assert!(path
+            .windows(2)
+            .all(|pair| { pair[0].x.abs_diff(pair[1].x) + pair[0].y.abs_diff(pair[1].y) == 1 }));
+    }
+
+    /// Moving forward costs 1 and turning costs 1000.
+    fn reindeer_moves(
+        grid: &Grid<char>,
+        (position, direction): (Vector2d<usize>, Direction),
+    ) -> Vec<((Vector2d<usize>, Direction), u32)> {
+        let mut moves = vec![
+            ((position, direction.rotate(1)), 1000),
+            ((position, direction.rotate(-1)), 1000),
+        ];
+
+        if let Some(next) = grid.step(position, direction, 1) {
+            if grid[next] != '#' {
+                moves.push(((next, direction), 1));
+            }
+        }
+
+        moves
+    }
+
+    #[test]
+    fn dijkstra_over_grid_states() {
+        let grid = Grid::from_text(MAZE, |ch| ch).unwrap();
+        let start = (Vector2d::new(0, 0), Direction::East);
+        let end = Vector2d::new(3, 2);
+
+        let result = dijkstra(start, |&state| reindeer_moves(&grid, state));
+
+        let best = Direction::ALL
+            .into_iter()
+            .filter_map(|dir| result.distance(&(end, dir)))
+            .min();
+
+        // Two turns are needed either going down first or after two steps,
+        // weaving through the middle takes four
+        assert_eq!(best, Some(2005));
+        assert_eq!(result.path_count(&(end, Direction::East)), 2);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let grid = Grid::from_text(MAZE, |ch| ch).unwrap();
+        let start = (Vector2d::new(0, 0), Direction::East);
+        let end = Vector2d::new(3, 2);
+
+        let (path, cost) = astar(
+            start,
+            |&state| reindeer_moves(&grid, state),
+            |(pos, _)| (pos.x.abs_diff(end.x) + pos.y.abs_diff(end.y)) as u32,
+            |(pos, _)| *pos == end,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 2005);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last().map(|(pos, _)| *pos), Some(end));
+
+        let unreachable = astar(
+            start,
+            |&state| reindeer_moves(&grid, state),
+            |_| 0,
+            |(pos, _)| *pos == Vector2d::new(3, 0),
+        );
+        assert_eq!(unreachable, None);
+    }
+
+    #[test]
+    fn dijkstra_counts_equal_cost_paths() {
+        // Diamond 0 -> {1, 2} -> 3 with equal costs, plus a pricier direct edge
+        let edges = |node: &u8| -> Vec<(u8, u32)> {
+            match node {
+                0 => vec![(1, 2), (2, 2), (3, 5)],
+                1 | 2 => vec![(3, 2)],
+                _ => vec![],
+            }
+        };
+
+        let result = dijkstra(0, edges);
+
+        assert_eq!(result.distance(&3), Some(4));
+        assert_eq!(result.path_count(&3), 2);
+        assert_eq!(result.predecessors[&3].len(), 2);
+    }
+
+    #[test]
+    fn dijkstra_with_zero_cost_cycle() {
+        let edges = |node: &u8| -> Vec<(u8, u32)> {
+            match node {
+                0 => vec![(1, 0)],
+                1 => vec![(0, 0), (2, 1)],
+                _ => vec![],
+            }
+        };
+
+        let result = dijkstra(0, edges);
+
+        assert_eq!(result.distance(&2), Some(1));
+        assert_eq!(result.path_to(&2), Some(vec![0, 1, 2]));
+        assert_eq!(result.path_count(&0), 1);
+        assert!(!result.predecessors.contains_key(&0));
+    }
+}
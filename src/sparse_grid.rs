@@ -0,0 +1,296 @@
+use std::{collections::HashMap, error::Error, fmt};
+
+use crate::grid::{Bounds, Grid, GridLike};
+use crate::vector::Vector2d;
+
+/// A position outside of the fixed bounds of a sparse grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutsideBounds {
+    pub position: Vector2d<isize>,
+    pub bounds: Bounds,
+}
+
+impl fmt::Display for OutsideBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Position {} is outside of the bounds {}..={}",
+            self.position, self.bounds.min, self.bounds.max
+        )
+    }
+}
+
+impl Error for OutsideBounds {}
+
+/// Grid storing only the occupied positions, which may be anywhere unless
+/// the grid was given fixed bounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Vector2d<isize>, T>,
+    bounds: Option<Bounds>,
+    bounding_box: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// An empty grid without bounds.
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+            bounding_box: None,
+        }
+    }
+
+    /// An empty grid that only accepts positions inside `bounds`.
+    pub fn with_bounds(bounds: Bounds) -> Self {
+        Self {
+            bounds: Some(bounds),
+            ..Self::new()
+        }
+    }
+
+    /// Cells of `grid` for which `keep` returns true, bounded by the area of
+    /// the grid.
+    pub fn from_grid(grid: &Grid<T>, keep: impl Fn(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        let mut sparse = match Bounds::of_size(grid.width(), grid.height()) {
+            Some(bounds) => Self::with_bounds(bounds),
+            None => Self::new(),
+        };
+
+        for (position, item) in GridLike::items(grid) {
+            if keep(item) {
+                sparse.cells.insert(position, item.clone());
+                sparse.grow_bounding_box(position);
+            }
+        }
+
+        sparse
+    }
+
+    /// Dense copy of the area covered by the grid, its fixed bounds or else
+    /// its bounding box, with the top left corner moved to the origin.
+    /// Empty positions are filled with the default value.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Default + Clone,
+    {
+        let Some(area) = self.bounds.or(self.bounding_box) else {
+            return Grid::new(0, 0);
+        };
+
+        let mut grid = Grid::new(area.width(), area.height());
+
+        for (position, item) in &self.cells {
+            let x = position.x.abs_diff(area.min.x);
+            let y = position.y.abs_diff(area.min.y);
+            grid[Vector2d::new(x, y)] = item.clone();
+        }
+
+        grid
+    }
+
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, position: Vector2d<isize>) -> Option<&T> {
+        self.cells.get(&position)
+    }
+
+    pub fn get_mut(&mut self, position: Vector2d<isize>) -> Option<&mut T> {
+        self.cells.get_mut(&position)
+    }
+
+    pub fn contains(&self, position: Vector2d<isize>) -> bool {
+        self.cells.contains_key(&position)
+    }
+
+    /// Stores `value` at `position`, returning the value it replaced.
+    pub fn insert(
+        &mut self,
+        position: Vector2d<isize>,
+        value: T,
+    ) -> Result<Option<T>, OutsideBounds> {
+        if let Some(bounds) = self.bounds.filter(|bounds| !bounds.contains(position)) {
+            return Err(OutsideBounds { position, bounds });
+        }
+
+        self.grow_bounding_box(position);
+        Ok(self.cells.insert(position, value))
+    }
+
+    pub fn remove(&mut self, position: Vector2d<isize>) -> Option<T> {
+        let removed = self.cells.remove(&position)?;
+
+        // Only positions on the edge can shrink the bounding box
+        if self.bounding_box.is_some_and(|bbox| {
+            position.x == bbox.min.x
+                || position.x == bbox.max.x
+                || position.y == bbox.min.y
+                || position.y == bbox.max.y
+        }) {
+            self.bounding_box = None;
+            for position in self.cells.keys().copied().collect::<Vec<_>>() {
+                self.grow_bounding_box(position);
+            }
+        }
+
+        Some(removed)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Vector2d<isize>, &T)> {
+        self.cells.iter().map(|(position, item)| (*position, item))
+    }
+
+    fn grow_bounding_box(&mut self, position: Vector2d<isize>) {
+        self.bounding_box = Some(match self.bounding_box {
+            Some(bbox) => bbox.including(position),
+            None => Bounds::at(position),
+        });
+    }
+}
+
+impl<T> GridLike for SparseGrid<T> {
+    type Item = T;
+
+    fn get_signed(&self, position: Vector2d<isize>) -> Option<&T> {
+        self.get(position)
+    }
+
+    fn in_bounds(&self, position: Vector2d<isize>) -> bool {
+        self.bounds.is_none_or(|bounds| bounds.contains(position))
+    }
+
+    fn bounding_box(&self) -> Option<Bounds> {
+        self.bounding_box
+    }
+
+    fn items(&self) -> impl Iterator<Item = (Vector2d<isize>, &T)> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direction::Direction;
+
+    #[test]
+    fn bounding_box_follows_items() {
+        let mut grid: SparseGrid<char> = SparseGrid::new();
+        assert_eq!(grid.bounding_box(), None);
+
+        grid.insert(Vector2d::new(-2, 3), 'a').unwrap();
+        grid.insert(Vector2d::new(4, -1), 'b').unwrap();
+        grid.insert(Vector2d::new(1, 1), 'c').unwrap();
+
+        assert_eq!(
+            grid.bounding_box(),
+            Some(Bounds {
+                min: Vector2d::new(-2, -1),
+                max: Vector2d::new(4, 3)
+            })
+        );
+        assert!(grid.in_bounds(Vector2d::new(100, 100)));
+
+        assert_eq!(grid.remove(Vector2d::new(4, -1)), Some('b'));
+        assert_eq!(
+            grid.bounding_box(),
+            Some(Bounds {
+                min: Vector2d::new(-2, 1),
+                max: Vector2d::new(1, 3)
+            })
+        );
+        assert_eq!(grid.remove(Vector2d::new(4, -1)), None);
+    }
+
+    #[test]
+    fn fixed_bounds_reject_outside_positions() {
+        let bounds = Bounds::of_size(3, 2).unwrap();
+        let mut grid = SparseGrid::with_bounds(bounds);
+
+        assert_eq!(grid.insert(Vector2d::new(2, 1), 1), Ok(None));
+        assert_eq!(grid.insert(Vector2d::new(2, 1), 2), Ok(Some(1)));
+        assert_eq!(
+            grid.insert(Vector2d::new(-1, 0), 3),
+            Err(OutsideBounds {
+                position: Vector2d::new(-1, 0),
+                bounds
+            })
+        );
+        assert!(!grid.in_bounds(Vector2d::new(3, 0)));
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    fn round_trip_through_dense_grid() {
+        let dense = Grid::from_text("..#\n#..\n", |ch| ch).unwrap();
+        let sparse = SparseGrid::from_grid(&dense, |&ch| ch == '#');
+
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse.get(Vector2d::new(2, 0)), Some(&'#'));
+        assert_eq!(sparse.get(Vector2d::new(0, 0)), None);
+        assert_eq!(sparse.bounds(), Bounds::of_size(3, 2));
+
+        let back = sparse.to_grid();
+        assert_eq!(
+            back.render(|&ch| if ch == '#' { '#' } else { '.' }),
+            "..#\n#.."
+        );
+    }
+
+    #[test]
+    fn to_grid_shifts_bounding_box_to_origin() {
+        let mut sparse = SparseGrid::new();
+        sparse.insert(Vector2d::new(-1, -1), 'a').unwrap();
+        sparse.insert(Vector2d::new(0, 1), 'b').unwrap();
+
+        let dense = sparse.to_grid();
+
+        assert_eq!(
+            dense.render(|&ch| if ch == '\0' { '.' } else { ch }),
+            "a.\n..\n.b"
+        );
+    }
+
+    /// Counts the occupied orthogonal neighbors of every item, for any kind
+    /// of grid.
+    fn crowded<G: GridLike>(grid: &G) -> usize {
+        grid.items()
+            .map(|(position, _)| grid.neighbors4(position).count())
+            .sum()
+    }
+
+    #[test]
+    fn grid_like_is_shared() {
+        let dense = Grid::from_text("##\n#.\n", |ch| ch).unwrap();
+        let sparse = SparseGrid::from_grid(&dense, |&ch| ch == '#');
+
+        assert_eq!(crowded(&dense), 8);
+        assert_eq!(crowded(&sparse), 4);
+
+        let directions: Vec<Direction> = sparse
+            .neighbors4(Vector2d::new(0, 0))
+            .map(|(direction, _, _)| direction)
+            .collect();
+        assert_eq!(directions.len(), 2);
+        assert_eq!(GridLike::bounding_box(&dense), Bounds::of_size(2, 2));
+    }
+}
@@ -1,6 +1,16 @@
-use std::ops::{Add, Mul, Sub};
+use std::{
+    error::Error,
+    fmt,
+    ops::{Add, AddAssign, Div, Index, IndexMut, Mul, Neg, Rem, Sub, SubAssign},
+    str::FromStr,
+};
+
+use num_traits::{CheckedAdd, CheckedSub, Euclid, One, Signed, Zero};
+
+use crate::direction::AsVector;
 
 #[derive(PartialEq, PartialOrd, Hash, Eq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector2d<T> {
     pub x: T,
     pub y: T,
@@ -12,6 +22,54 @@ impl<T> Vector2d<T> {
     }
 }
 
+impl<T: fmt::Display> fmt::Display for Vector2d<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Why a string couldn't be parsed as a `Vector2d`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseVectorError<E> {
+    /// There's no comma between the components.
+    MissingComma,
+    /// One of the components isn't a valid number.
+    Component(E),
+}
+
+impl<E: fmt::Display> fmt::Display for ParseVectorError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseVectorError::MissingComma => write!(f, "Expected two components separated by ','"),
+            ParseVectorError::Component(err) => write!(f, "Invalid component: {err}"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseVectorError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseVectorError::MissingComma => None,
+            ParseVectorError::Component(err) => Some(err),
+        }
+    }
+}
+
+/// Parses `x,y`, allowing whitespace around each component and whatever
+/// signs `T` accepts, e.g. `3,4` or ` -3, +4`.
+impl<T: FromStr> FromStr for Vector2d<T> {
+    type Err = ParseVectorError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s.split_once(',').ok_or(ParseVectorError::MissingComma)?;
+
+        Ok(Self {
+            x: x.trim().parse().map_err(ParseVectorError::Component)?,
+            y: y.trim().parse().map_err(ParseVectorError::Component)?,
+        })
+    }
+}
+
 impl<T> Add for Vector2d<T>
 where
     T: Add<Output = T>,
@@ -54,6 +112,68 @@ where
     }
 }
 
+impl<T> Div for Vector2d<T>
+where
+    T: Div<Output = T>,
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x / rhs.x,
+            y: self.y / rhs.y,
+        }
+    }
+}
+
+impl<T> Rem for Vector2d<T>
+where
+    T: Rem<Output = T>,
+{
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x % rhs.x,
+            y: self.y % rhs.y,
+        }
+    }
+}
+
+impl<T> Neg for Vector2d<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T> AddAssign for Vector2d<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T> SubAssign for Vector2d<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
 impl<T> Vector2d<T>
 where
     T: Mul<Output = T> + Copy + Clone,
@@ -76,3 +196,580 @@ impl<T> Vector2d<T> {
         Ok(Vector2d { x, y })
     }
 }
+
+/// Distance between two numbers, which doesn't underflow for unsigned ones.
+fn abs_diff<T: Sub<Output = T> + PartialOrd>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// Greatest common divisor of the absolute values, zero only if both are.
+pub(crate) fn gcd<T: Signed + Copy>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+
+    while !b.is_zero() {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+impl<T> Vector2d<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + PartialOrd + Copy,
+{
+    /// Number of orthogonal steps between both positions.
+    pub fn manhattan_distance(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Number of steps between both positions when diagonal steps are
+    /// allowed.
+    pub fn chebyshev_distance(self, other: Self) -> T {
+        let dx = abs_diff(self.x, other.x);
+        let dy = abs_diff(self.y, other.y);
+
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Z component of the 3D cross product, positive when `other` is
+    /// counterclockwise from `self` with y pointing up.
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+}
+
+impl<T: Euclid> Vector2d<T> {
+    /// Component-wise remainder that's never negative, for wrapping around
+    /// a world of size `modulus`.
+    pub fn rem_euclid(self, modulus: Self) -> Self {
+        Self {
+            x: self.x.rem_euclid(&modulus.x),
+            y: self.y.rem_euclid(&modulus.y),
+        }
+    }
+
+    /// Wraps the position around a toroidal map of the given size.
+    pub fn wrap(self, width: T, height: T) -> Self {
+        self.rem_euclid(Vector2d::new(width, height))
+    }
+}
+
+impl Vector2d<usize> {
+    /// Moves by a signed `delta`, unless a component would become negative
+    /// or overflow.
+    pub fn checked_add_signed(self, delta: Vector2d<isize>) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add_signed(delta.x)?,
+            y: self.y.checked_add_signed(delta.y)?,
+        })
+    }
+
+    /// Signed difference `self - other`, unless it doesn't fit in `isize`.
+    pub fn checked_signed_diff(self, other: Self) -> Option<Vector2d<isize>> {
+        Some(Vector2d {
+            x: self.x.checked_signed_diff(other.x)?,
+            y: self.y.checked_signed_diff(other.y)?,
+        })
+    }
+
+    /// Position `n` steps away towards `direction`, unless a component
+    /// would become negative or overflow.
+    pub fn checked_offset<D: AsVector>(self, direction: D, n: usize) -> Option<Self> {
+        let delta: Vector2d<isize> = direction.as_vector();
+        let n = isize::try_from(n).ok()?;
+
+        self.checked_add_signed(Vector2d::new(
+            delta.x.checked_mul(n)?,
+            delta.y.checked_mul(n)?,
+        ))
+    }
+}
+
+impl<T: Signed + Copy> Vector2d<T> {
+    pub fn abs(self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+        }
+    }
+
+    pub fn signum(self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+
+    /// Smallest integer step in the same direction, dividing both
+    /// components by their greatest common divisor. The zero vector stays
+    /// the same.
+    pub fn reduced(self) -> Self {
+        let divisor = gcd(self.x, self.y);
+
+        if divisor.is_zero() {
+            self
+        } else {
+            Self {
+                x: self.x / divisor,
+                y: self.y / divisor,
+            }
+        }
+    }
+}
+
+/// Vector with any number of dimensions, with the same operations as
+/// `Vector2d`.
+#[derive(PartialEq, PartialOrd, Hash, Eq, Clone, Copy, Debug)]
+pub struct VectorN<T, const N: usize>(pub [T; N]);
+
+pub type Vector3d<T> = VectorN<T, 3>;
+
+impl<T, const N: usize> VectorN<T, N> {
+    pub fn new(components: [T; N]) -> Self {
+        Self(components)
+    }
+
+    pub fn try_components_into<U>(self) -> Result<VectorN<U, N>, <T as TryInto<U>>::Error>
+    where
+        T: TryInto<U>,
+    {
+        let mut components = Vec::with_capacity(N);
+
+        for component in self.0 {
+            components.push(component.try_into()?);
+        }
+
+        match components.try_into() {
+            Ok(components) => Ok(VectorN(components)),
+            Err(_) => unreachable!("one component was converted per dimension"),
+        }
+    }
+}
+
+impl<T, const N: usize> Index<usize> for VectorN<T, N> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &T {
+        &self.0[axis]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for VectorN<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut T {
+        &mut self.0[axis]
+    }
+}
+
+/// Component-wise binary operator and its assigning form, if any.
+macro_rules! vector_n_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<T: $trait<Output = T> + Copy, const N: usize> $trait for VectorN<T, N> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self(std::array::from_fn(|axis| self.0[axis] $op rhs.0[axis]))
+            }
+        }
+    };
+    ($trait:ident, $method:ident, $op:tt, $assign_trait:ident, $assign_method:ident) => {
+        vector_n_op!($trait, $method, $op);
+
+        impl<T: $assign_trait + Copy, const N: usize> $assign_trait for VectorN<T, N> {
+            fn $assign_method(&mut self, rhs: Self) {
+                for axis in 0..N {
+                    self.0[axis].$assign_method(rhs.0[axis]);
+                }
+            }
+        }
+    };
+}
+
+vector_n_op!(Add, add, +, AddAssign, add_assign);
+vector_n_op!(Sub, sub, -, SubAssign, sub_assign);
+vector_n_op!(Mul, mul, *);
+vector_n_op!(Div, div, /);
+vector_n_op!(Rem, rem, %);
+
+impl<T: Neg<Output = T> + Copy, const N: usize> Neg for VectorN<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.map(|component| -component))
+    }
+}
+
+impl<T: Copy, const N: usize> VectorN<T, N> {
+    pub fn scalar_mul(self, factor: T) -> Self
+    where
+        T: Mul<Output = T>,
+    {
+        Self(self.0.map(|component| component * factor))
+    }
+
+    pub fn rem_euclid(self, modulus: Self) -> Self
+    where
+        T: Euclid,
+    {
+        Self(std::array::from_fn(|axis| {
+            self.0[axis].rem_euclid(&modulus.0[axis])
+        }))
+    }
+
+    /// Positions one step away along a single axis, skipping the ones that
+    /// would overflow.
+    pub fn orthogonal_neighbors(self) -> impl Iterator<Item = Self>
+    where
+        T: One + CheckedAdd + CheckedSub,
+    {
+        (0..N).flat_map(move |axis| {
+            let component = self.0[axis];
+
+            [
+                component.checked_sub(&T::one()),
+                component.checked_add(&T::one()),
+            ]
+            .into_iter()
+            .flatten()
+            .map(move |moved| {
+                let mut neighbor = self;
+                neighbor.0[axis] = moved;
+                neighbor
+            })
+        })
+    }
+
+    /// Every position at most one step away along each axis, diagonals
+    /// included, skipping the ones that would overflow.
+    pub fn neighbors(self) -> impl Iterator<Item = Self>
+    where
+        T: One + CheckedAdd + CheckedSub,
+    {
+        // Each offset is a number in base 3, with a digit of 0, 1 or 2
+        // meaning a step of -1, 0 or +1 along that axis
+        let offsets = 3_usize.pow(N as u32);
+        let center = (offsets - 1) / 2;
+
+        (0..offsets)
+            .filter(move |&offset| offset != center)
+            .filter_map(move |mut offset| {
+                let mut neighbor = self;
+
+                for component in neighbor.0.iter_mut() {
+                    *component = match offset % 3 {
+                        0 => component.checked_sub(&T::one())?,
+                        1 => *component,
+                        _ => component.checked_add(&T::one())?,
+                    };
+                    offset /= 3;
+                }
+
+                Some(neighbor)
+            })
+    }
+}
+
+impl<T, const N: usize> VectorN<T, N>
+where
+    T: Zero + Sub<Output = T> + Mul<Output = T> + PartialOrd + Copy,
+{
+    pub fn manhattan_distance(self, other: Self) -> T {
+        (0..N).fold(T::zero(), |total, axis| {
+            total + abs_diff(self.0[axis], other.0[axis])
+        })
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> T {
+        (0..N).fold(T::zero(), |max, axis| {
+            let distance = abs_diff(self.0[axis], other.0[axis]);
+            if distance > max {
+                distance
+            } else {
+                max
+            }
+        })
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        (0..N).fold(T::zero(), |total, axis| {
+            total + self.0[axis] * other.0[axis]
+        })
+    }
+}
+
+impl<T, const N: usize> VectorN<T, N>
+where
+    T: Signed + Copy,
+{
+    pub fn abs(self) -> Self {
+        Self(self.0.map(|component| component.abs()))
+    }
+
+    pub fn signum(self) -> Self {
+        Self(self.0.map(|component| component.signum()))
+    }
+
+    /// Smallest integer step in the same direction, see
+    /// `Vector2d::reduced`.
+    pub fn reduced(self) -> Self {
+        let divisor = self.0.into_iter().fold(T::zero(), gcd);
+
+        if divisor.is_zero() {
+            self
+        } else {
+            Self(self.0.map(|component| component / divisor))
+        }
+    }
+}
+
+impl<T> Vector3d<T>
+where
+    T: Mul<Output = T> + Sub<Output = T> + Copy,
+{
+    pub fn cross(self, other: Self) -> Self {
+        let [ax, ay, az] = self.0;
+        let [bx, by, bz] = other.0;
+
+        Self([ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx])
+    }
+}
+
+impl<T> From<Vector2d<T>> for VectorN<T, 2> {
+    fn from(vector: Vector2d<T>) -> Self {
+        Self([vector.x, vector.y])
+    }
+}
+
+impl<T> From<VectorN<T, 2>> for Vector2d<T> {
+    fn from(VectorN([x, y]): VectorN<T, 2>) -> Self {
+        Self { x, y }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operators() {
+        let mut a = Vector2d::new(7, -3);
+        let b = Vector2d::new(2, 2);
+
+        assert_eq!(-a, Vector2d::new(-7, 3));
+        assert_eq!(a / b, Vector2d::new(3, -1));
+        assert_eq!(a % b, Vector2d::new(1, -1));
+        assert_eq!(a.rem_euclid(b), Vector2d::new(1, 1));
+
+        a += b;
+        assert_eq!(a, Vector2d::new(9, -1));
+        a -= b.scalar_mul(2);
+        assert_eq!(a, Vector2d::new(5, -5));
+    }
+
+    #[test]
+    fn wrap_around_with_rem_euclid() {
+        let size = Vector2d::new(11, 7);
+        let position = Vector2d::new(2, 4) + Vector2d::new(2, -3).scalar_mul(5);
+
+        assert_eq!(position.rem_euclid(size), Vector2d::new(1, 3));
+    }
+
+    #[test]
+    fn distances() {
+        let a = Vector2d::new(1_usize, 8);
+        let b = Vector2d::new(4_usize, 2);
+
+        assert_eq!(a.manhattan_distance(b), 9);
+        assert_eq!(b.manhattan_distance(a), 9);
+        assert_eq!(a.chebyshev_distance(b), 6);
+    }
+
+    #[test]
+    fn products() {
+        let east = Vector2d::new(1, 0);
+        let north = Vector2d::new(0, 1);
+
+        assert_eq!(east.dot(north), 0);
+        assert_eq!(Vector2d::new(2, 3).dot(Vector2d::new(4, -1)), 5);
+        assert_eq!(east.cross(north), 1);
+        assert_eq!(north.cross(east), -1);
+    }
+
+    #[test]
+    fn signs_and_reduction() {
+        let v = Vector2d::new(-6_i64, 9);
+
+        assert_eq!(v.abs(), Vector2d::new(6, 9));
+        assert_eq!(v.signum(), Vector2d::new(-1, 1));
+        assert_eq!(v.reduced(), Vector2d::new(-2, 3));
+        assert_eq!(Vector2d::new(0, -4).reduced(), Vector2d::new(0, -1));
+        assert_eq!(Vector2d::new(0, 0).reduced(), Vector2d::new(0, 0));
+    }
+
+    #[test]
+    fn vector_n_operators() {
+        let mut a = Vector3d::new([1, -2, 3]);
+        let b = Vector3d::new([4, 5, -6]);
+
+        assert_eq!(a + b, VectorN([5, 3, -3]));
+        assert_eq!(a - b, VectorN([-3, -7, 9]));
+        assert_eq!(a * b, VectorN([4, -10, -18]));
+        assert_eq!(b / VectorN([2, 2, 4]), VectorN([2, 2, -1]));
+        assert_eq!(b % VectorN([3, 3, 4]), VectorN([1, 2, -2]));
+        assert_eq!(b.rem_euclid(VectorN([3, 3, 4])), VectorN([1, 2, 2]));
+        assert_eq!(-a, VectorN([-1, 2, -3]));
+        assert_eq!(a.scalar_mul(2), VectorN([2, -4, 6]));
+
+        a += b;
+        a -= VectorN([1, 1, 1]);
+        assert_eq!(a, VectorN([4, 2, -4]));
+        a[2] = 0;
+        assert_eq!(a[2], 0);
+    }
+
+    #[test]
+    fn vector_n_geometry() {
+        let a = Vector3d::new([1, 0, 0]);
+        let b = Vector3d::new([0, 1, 0]);
+
+        assert_eq!(a.cross(b), VectorN([0, 0, 1]));
+        assert_eq!(a.dot(b), 0);
+        assert_eq!(
+            VectorN([1, 8, -2]).manhattan_distance(VectorN([4, 2, 0])),
+            11
+        );
+        assert_eq!(
+            VectorN([1, 8, -2]).chebyshev_distance(VectorN([4, 2, 0])),
+            6
+        );
+        assert_eq!(VectorN([-4, 6, 0]).abs(), VectorN([4, 6, 0]));
+        assert_eq!(VectorN([-4, 6, 0]).signum(), VectorN([-1, 1, 0]));
+        assert_eq!(VectorN([-4, 6, 10]).reduced(), VectorN([-2, 3, 5]));
+        assert_eq!(VectorN([0, 0, 0]).reduced(), VectorN([0, 0, 0]));
+
+        let empty: VectorN<i32, 0> = VectorN([]);
+        assert_eq!(empty.manhattan_distance(empty), 0);
+        assert_eq!(empty.chebyshev_distance(empty), 0);
+        assert_eq!(empty.dot(empty), 0);
+    }
+
+    #[test]
+    fn vector_n_neighbors() {
+        let center = Vector3d::new([5_i32, 5, 5]);
+
+        assert_eq!(center.orthogonal_neighbors().count(), 6);
+        assert_eq!(center.neighbors().count(), 26);
+        assert!(center
+            .neighbors()
+            .all(|neighbor| neighbor.chebyshev_distance(center) == 1));
+
+        let corner = VectorN([0_usize, 0]);
+        let mut neighbors: Vec<_> = corner.neighbors().collect();
+        neighbors.sort_by_key(|neighbor| neighbor.0);
+        assert_eq!(
+            neighbors,
+            vec![VectorN([0, 1]), VectorN([1, 0]), VectorN([1, 1])]
+        );
+        assert_eq!(corner.orthogonal_neighbors().count(), 2);
+    }
+
+    #[test]
+    fn conversions_between_2d_and_n() {
+        let v = Vector2d::new(3_i64, -4);
+        let n: VectorN<i64, 2> = v.into();
+
+        assert_eq!(n, VectorN([3, -4]));
+        assert_eq!(Vector2d::from(n), v);
+        assert_eq!(
+            VectorN([1_i64, 2, 3]).try_components_into::<u8>(),
+            Ok(VectorN([1, 2, 3]))
+        );
+        assert!(VectorN([1_i64, -2]).try_components_into::<u8>().is_err());
+    }
+
+    #[test]
+    fn checked_unsigned_movement() {
+        use crate::direction::{Direction, Direction8};
+
+        let position = Vector2d::new(2_usize, 1);
+
+        assert_eq!(
+            position.checked_add_signed(Vector2d::new(-2, 3)),
+            Some(Vector2d::new(0, 4))
+        );
+        assert_eq!(position.checked_add_signed(Vector2d::new(0, -2)), None);
+        assert_eq!(
+            position.checked_signed_diff(Vector2d::new(5, 0)),
+            Some(Vector2d::new(-3, 1))
+        );
+
+        assert_eq!(
+            position.checked_offset(Direction::West, 2),
+            Some(Vector2d::new(0, 1))
+        );
+        assert_eq!(position.checked_offset(Direction::West, 3), None);
+        assert_eq!(
+            position.checked_offset(Direction8::SouthEast, 4),
+            Some(Vector2d::new(6, 5))
+        );
+        assert_eq!(position.checked_offset(Direction::East, usize::MAX), None);
+    }
+
+    #[test]
+    fn wrap_on_toroidal_map() {
+        assert_eq!(Vector2d::new(-1_i32, 7).wrap(11, 7), Vector2d::new(10, 0));
+        assert_eq!(Vector2d::new(12_usize, 3).wrap(11, 7), Vector2d::new(1, 3));
+    }
+
+    #[test]
+    fn parse_and_display() {
+        assert_eq!("3,4".parse(), Ok(Vector2d::new(3_u32, 4)));
+        assert_eq!(" -3 , +4\n".parse(), Ok(Vector2d::new(-3_i64, 4)));
+        assert_eq!(
+            "3 4".parse::<Vector2d<i32>>(),
+            Err(ParseVectorError::MissingComma)
+        );
+        assert!(matches!(
+            "3,-4".parse::<Vector2d<usize>>(),
+            Err(ParseVectorError::Component(_))
+        ));
+        assert!("1,2,3".parse::<Vector2d<i32>>().is_err());
+
+        assert_eq!(Vector2d::new(-3, 4).to_string(), "(-3, 4)");
+    }
+
+    #[test]
+    fn parse_robot_line() {
+        let line = "p=0,4 v=3,-3";
+        let (position, velocity) = line.split_once(' ').unwrap();
+
+        let position: Vector2d<i32> = position.strip_prefix("p=").unwrap().parse().unwrap();
+        let velocity: Vector2d<i32> = velocity.strip_prefix("v=").unwrap().parse().unwrap();
+
+        assert_eq!(
+            (position, velocity),
+            (Vector2d::new(0, 4), Vector2d::new(3, -3))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let vector = Vector2d::new(-3_i64, 4);
+        let text = toml::to_string(&vector).unwrap();
+
+        assert_eq!(text, "x = -3\ny = 4\n");
+        assert_eq!(toml::from_str::<Vector2d<i64>>(&text).unwrap(), vector);
+    }
+}
@@ -0,0 +1,116 @@
+use std::{fs, path::Path};
+
+use toml::{Table, Value};
+
+use crate::{error::AocError, solution::Answer};
+
+/// Outcome of checking an answer against the known one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+/// Known answers, loaded from a TOML file with a table per day:
+///
+/// ```toml
+/// [day05]
+/// part1 = 143
+/// part2 = 123
+/// ```
+///
+/// Text answers are written as strings. Missing days or parts are
+/// reported as unknown.
+pub struct KnownAnswers {
+    table: Table,
+}
+
+impl KnownAnswers {
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        let text = fs::read_to_string(path).map_err(|source| AocError::Io {
+            path: path.display().to_string(),
+            source,
+        })?;
+
+        Self::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, AocError> {
+        let table: Table = text.parse().map_err(|err: toml::de::Error| {
+            let offset = err.span().map_or(0, |span| span.start);
+            let before = &text[..offset];
+            let line = before.matches('\n').count() + 1;
+            let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+
+            AocError::parse(line, column, err.message())
+        })?;
+
+        Ok(Self { table })
+    }
+
+    fn expected(&self, day: u8, part: u8) -> Option<&Value> {
+        self.table
+            .get(&format!("day{day:02}"))?
+            .get(format!("part{part}"))
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        let Some(expected) = self.expected(day, part) else {
+            return Verdict::Unknown;
+        };
+
+        let matches = match (expected, answer) {
+            (Value::Integer(expected), Answer::Number(actual)) => *expected as i128 == *actual,
+            (Value::String(expected), answer) => *expected == answer.to_string(),
+            _ => false,
+        };
+
+        if matches {
+            Verdict::Pass
+        } else {
+            let expected = match expected {
+                Value::String(text) => text.clone(),
+                other => other.to_string(),
+            };
+            Verdict::Fail { expected }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[day01]
+part1 = 11
+
+[day02]
+part1 = 2
+part2 = "4"
+"#;
+
+    #[test]
+    fn checks_numbers_and_strings() {
+        let known = KnownAnswers::parse(ANSWERS).unwrap();
+
+        assert_eq!(known.check(1, 1, &Answer::from(11u64)), Verdict::Pass);
+        assert_eq!(
+            known.check(1, 1, &Answer::from(12u64)),
+            Verdict::Fail {
+                expected: "11".to_string()
+            }
+        );
+        assert_eq!(known.check(1, 2, &Answer::from(31u64)), Verdict::Unknown);
+        assert_eq!(known.check(2, 2, &Answer::from(4u64)), Verdict::Pass);
+        assert_eq!(known.check(3, 1, &Answer::from(1u64)), Verdict::Unknown);
+    }
+
+    #[test]
+    fn reports_syntax_errors_with_position() {
+        let err = KnownAnswers::parse("[day01]\npart1 = = 3\n").err().unwrap();
+
+        assert!(matches!(err, AocError::Parse { line: 2, .. }));
+    }
+}
@@ -0,0 +1,49 @@
+//! Checks the solvers against known answers for real puzzle inputs. Both
+//! the day's input (see `InputSource::for_day`) and `answers/dayNN.txt` must
+//! exist for a day to be checked, otherwise it is skipped. Answer files hold
+//! the Part 1 answer on the first line and the Part 2 answer on the second.
+
+use std::{fs, path::Path};
+
+use advent_of_code_2024::{days, InputSource};
+
+#[test]
+fn real_inputs_match_known_answers() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut failures: Vec<String> = Vec::new();
+
+    for day in days::available() {
+        let answers_path = root.join(format!("answers/day{day:02}.txt"));
+
+        let (Ok(input), Ok(expected)) = (
+            InputSource::for_day(day).read(),
+            fs::read_to_string(&answers_path),
+        ) else {
+            continue;
+        };
+
+        let solver = days::solver(day).expect("Registered day");
+        let answers = match solver(&input) {
+            Ok(answers) => answers,
+            Err(err) => {
+                failures.push(format!("Day {day}: {err}"));
+                continue;
+            }
+        };
+
+        let mut expected = expected.lines().map(str::trim);
+
+        for (part, actual) in [(1, &answers.part1), (2, &answers.part2)] {
+            match expected.next() {
+                Some(expected) if !expected.is_empty() && expected != actual.to_string() => {
+                    failures.push(format!(
+                        "Day {day} part {part}: expected {expected}, got {actual}"
+                    ))
+                }
+                _ => (),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}